@@ -0,0 +1,89 @@
+use plotly::animation::{
+    AnimationMode, AnimationOptions, Easing, Frame, FrameSettings, Transition,
+};
+use plotly::common::{Anchor, Mode, Title};
+use plotly::layout::{
+    Animate, Axis, Button, CurrentValue, Slider, SliderStep, UpdateMenu, UpdateMenuType,
+};
+use plotly::{Layout, Plot, Scatter};
+
+fn wave(t: f64) -> (Vec<f64>, Vec<f64>) {
+    let x: Vec<f64> = (0..100).map(|i| i as f64 / 10.0).collect();
+    let y = x.iter().map(|x| (x - t).sin()).collect();
+    (x, y)
+}
+
+fn animated_wave() {
+    let n_frames = 30;
+    let (x, y) = wave(0.0);
+    let mut plot = Plot::new();
+    plot.add_trace(Scatter::new(x, y).mode(Mode::Lines));
+
+    let mut steps = Vec::with_capacity(n_frames);
+    for i in 0..n_frames {
+        let t = i as f64 / 5.0;
+        let name = format!("{:.1}", t);
+        let (x, y) = wave(t);
+        plot.add_frame(
+            Frame::new()
+                .name(&name)
+                .add_trace(Scatter::new(x, y).mode(Mode::Lines)),
+        );
+        steps.push(
+            SliderStep::new().label(&name).animate(
+                Animate::new().frames(vec![name.as_str()]).options(
+                    AnimationOptions::new()
+                        .mode(AnimationMode::Immediate)
+                        .frame(FrameSettings::new().duration(0.0).redraw(false)),
+                ),
+            ),
+        );
+    }
+
+    let play = AnimationOptions::new()
+        .frame(FrameSettings::new().duration(50.0).redraw(false))
+        .transition(Transition::new().duration(0.0).easing(Easing::Linear))
+        .from_current(true);
+    let menu = UpdateMenu::new()
+        .menu_type(UpdateMenuType::Buttons)
+        .x(0.0)
+        .x_anchor(Anchor::Right)
+        .y(0.0)
+        .y_anchor(Anchor::Top)
+        .buttons(vec![
+            Button::new()
+                .label("Play")
+                .animate(Animate::new().options(play)),
+            Button::new().label("Pause").animate(Animate::pause()),
+        ]);
+    let slider = Slider::new()
+        .steps(steps)
+        .current_value(CurrentValue::new().prefix("t = "));
+    let layout = Layout::new()
+        .title(Title::new("Travelling wave"))
+        .yaxis(Axis::new().range(vec![-1.2, 1.2]))
+        .update_menus(vec![menu])
+        .sliders(vec![slider]);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn autoplay() {
+    let mut plot = Plot::new();
+    plot.add_trace(Scatter::new(vec![1, 2, 3], vec![1, 2, 3]).mode(Mode::Markers));
+    plot.add_frame(Frame::new().add_trace(Scatter::new(vec![1, 2, 3], vec![3, 1, 2])));
+    plot.add_frame(Frame::new().add_trace(Scatter::new(vec![1, 2, 3], vec![2, 3, 1])));
+    plot.set_layout(Layout::new().yaxis(Axis::new().range(vec![0.0, 4.0])));
+    plot.set_animation_options(
+        AnimationOptions::new()
+            .frame(FrameSettings::new().duration(1000.0).redraw(false))
+            .transition(Transition::new().duration(500.0).easing(Easing::CubicInOut)),
+    );
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    animated_wave();
+    autoplay();
+    Ok(())
+}
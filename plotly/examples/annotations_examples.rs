@@ -0,0 +1,94 @@
+use plotly::common::{Anchor, AxisReference, Font, Mode};
+use plotly::layout::{ClickToShow, HAlign};
+use plotly::{Annotation, Layout, NamedColor, Plot, Scatter, Subplots};
+
+fn annotated_time_series() {
+    let trace = Scatter::new(
+        vec![
+            "2020-01-01",
+            "2020-02-01",
+            "2020-03-01",
+            "2020-04-01",
+            "2020-05-01",
+        ],
+        vec![10, 12, 9, 4, 7],
+    )
+    .mode(Mode::LinesMarkers);
+    let layout = Layout::new()
+        .add_annotation(
+            Annotation::new()
+                .text("Lockdown")
+                .x("2020-04-01")
+                .y(4)
+                .x_ref(AxisReference::axis("x"))
+                .y_ref(AxisReference::axis("y"))
+                .show_arrow(true)
+                .arrow_head(2)
+                .ax(0)
+                .ay(-60),
+        )
+        .add_annotation(
+            Annotation::new()
+                .text("Monthly average<br>of daily values")
+                .x(0.0)
+                .y(1.0)
+                .x_ref(AxisReference::Paper)
+                .y_ref(AxisReference::Paper)
+                .x_anchor(Anchor::Left)
+                .y_anchor(Anchor::Bottom)
+                .align(HAlign::Left)
+                .show_arrow(false),
+        );
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn styled_annotations() {
+    let trace = Scatter::new(vec![0, 1, 2, 3, 4, 5], vec![0, 1, 3, 2, 4, 3]);
+    let layout = Layout::new().annotations(vec![
+        Annotation::new()
+            .text("max")
+            .x(4)
+            .y(4)
+            .x_ref(AxisReference::axis("x"))
+            .y_ref(AxisReference::axis("y"))
+            .font(Font::new().size(16).color(NamedColor::White))
+            .background_color(NamedColor::IndianRed)
+            .border_color(NamedColor::DarkRed)
+            .border_width(2.0)
+            .border_pad(4.0)
+            .arrow_color(NamedColor::DarkRed)
+            .ax(-40)
+            .ay(-30),
+        Annotation::new()
+            .text("click the point")
+            .hover_text("shown when clicking the point at (2, 3)")
+            .x(2)
+            .y(3)
+            .x_ref(AxisReference::axis("x"))
+            .y_ref(AxisReference::axis("y"))
+            .click_to_show(ClickToShow::OnOff),
+    ]);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn subplot_titles() {
+    let mut plot = Subplots::new(1, 2)
+        .subplot_titles(vec!["Linear", "Quadratic"])
+        .build();
+    plot.add_trace_at(Scatter::new(vec![1, 2, 3], vec![1, 2, 3]), 1, 1);
+    plot.add_trace_at(Scatter::new(vec![1, 2, 3], vec![1, 4, 9]), 1, 2);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    annotated_time_series();
+    styled_annotations();
+    subplot_titles();
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use plotly::{Candlestick, Plot};
+use plotly::layout::{Axis, RangeSelector, RangeSlider, SelectorButton, SelectorStep, StepMode};
+use plotly::{Candlestick, Layout, Plot};
 use rand_distr::{Distribution, Normal};
 
 fn geometric_brownian_motion(s_0: f64, dt: f64, n: usize, drift: f64, diffusion: f64) -> Vec<f64> {
@@ -109,8 +110,78 @@ fn gbm_simple_candlestick_chart() {
     plot.show();
 }
 
+fn daily_dates(year: usize, n: usize) -> Vec<String> {
+    let mut dates = Vec::with_capacity(n);
+    let (mut year, mut month, mut day) = (year, 1, 1);
+    while dates.len() < n {
+        dates.push(format!("{}-{:02}-{:02}", year, month, day));
+        let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days_in_month = match month {
+            2 if leap => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+        day += 1;
+        if day > days_in_month {
+            day = 1;
+            month += 1;
+        }
+        if month > 12 {
+            month = 1;
+            year += 1;
+        }
+    }
+    dates
+}
+
+fn candlestick_chart_with_range_selector() {
+    let n = 1_000;
+    let x = daily_dates(2017, n);
+    let mid = geometric_brownian_motion(100.0, 1.0 / 365.0, n, 0.15, 0.5);
+    let open = mid.iter().map(|m| 0.99 * m).collect();
+    let high = mid.iter().map(|m| 1.04 * m).collect();
+    let low = mid.iter().map(|m| 0.96 * m).collect();
+    let close = mid.iter().map(|m| 1.01 * m).collect();
+
+    let trace = Candlestick::new(x, open, high, low, close);
+    let buttons = vec![
+        SelectorButton::new()
+            .count(1.0)
+            .label("1m")
+            .step(SelectorStep::Month)
+            .step_mode(StepMode::Backward),
+        SelectorButton::new()
+            .count(6.0)
+            .label("6m")
+            .step(SelectorStep::Month)
+            .step_mode(StepMode::Backward),
+        SelectorButton::new()
+            .count(1.0)
+            .label("YTD")
+            .step(SelectorStep::Year)
+            .step_mode(StepMode::ToDate),
+        SelectorButton::new()
+            .count(1.0)
+            .label("1y")
+            .step(SelectorStep::Year)
+            .step_mode(StepMode::Backward),
+        SelectorButton::new().step(SelectorStep::All),
+    ];
+    let layout = Layout::new().xaxis(
+        Axis::new()
+            .range_selector(RangeSelector::new().buttons(buttons))
+            .range_slider(RangeSlider::new().visible(true).thickness(0.1)),
+    );
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    plot.show();
+}
+
 fn main() -> std::io::Result<()> {
     simple_candlestick_chart();
     gbm_simple_candlestick_chart();
+    candlestick_chart_with_range_selector();
     Ok(())
 }
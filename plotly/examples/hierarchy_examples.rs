@@ -0,0 +1,70 @@
+use plotly::common::hierarchy::{BranchValues, Hierarchy, HierarchyInfo, Node};
+use plotly::treemap::{Packing, Tiling};
+use plotly::{Plot, Sunburst, Treemap};
+
+fn basic_sunburst_chart() {
+    let trace = Sunburst::new(
+        vec![
+            "Eve", "Cain", "Seth", "Enos", "Noam", "Abel", "Awan", "Enoch", "Azura",
+        ],
+        vec![
+            "", "Eve", "Eve", "Seth", "Seth", "Eve", "Eve", "Awan", "Eve",
+        ],
+    )
+    .values(vec![10.0, 14.0, 12.0, 10.0, 2.0, 6.0, 6.0, 4.0, 4.0]);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn sunburst_chart_from_tree() {
+    let tree = Node::new("Eve")
+        .value(65.0)
+        .child(Node::new("Cain").value(14.0))
+        .child(Node::new("Seth").value(12.0).children(vec![
+            Node::new("Enos").value(10.0),
+            Node::new("Noam").value(2.0),
+        ]))
+        .child(Node::new("Abel").value(6.0))
+        .child(
+            Node::new("Awan")
+                .value(6.0)
+                .child(Node::new("Enoch").value(4.0)),
+        )
+        .child(Node::new("Azura").value(4.0));
+    let trace = Sunburst::from_hierarchy(Hierarchy::from_tree(vec![tree]))
+        .branch_values(BranchValues::Total)
+        .max_depth(2);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn treemap_chart_from_paths() {
+    let hierarchy = Hierarchy::from_paths(vec![
+        (vec!["World", "Europe", "France"], 67.0),
+        (vec!["World", "Europe", "Germany"], 83.0),
+        (vec!["World", "Europe", "Italy"], 60.0),
+        (vec!["World", "Asia", "Japan"], 126.0),
+        (vec!["World", "Asia", "India"], 1380.0),
+        (vec!["World", "Americas", "Brazil"], 212.0),
+        (vec!["World", "Americas", "Canada"], 38.0),
+    ]);
+    let trace = Treemap::from_hierarchy(hierarchy)
+        .text_info(vec![
+            HierarchyInfo::Label,
+            HierarchyInfo::Value,
+            HierarchyInfo::PercentParent,
+        ])
+        .tiling(Tiling::new().packing(Packing::Squarify));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    basic_sunburst_chart();
+    sunburst_chart_from_tree();
+    treemap_chart_from_paths();
+    Ok(())
+}
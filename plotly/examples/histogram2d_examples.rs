@@ -0,0 +1,43 @@
+use plotly::common::{ColorScale, ColorScalePalette};
+use plotly::contour::{Contours, ContoursColoring};
+use plotly::histogram::HistNorm;
+use plotly::{Histogram2d, Histogram2dContour, Plot};
+use rand_distr::{Distribution, Normal};
+
+fn sample_normal_xy(n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut rng = rand::thread_rng();
+    let x_dist = Normal::new(0.0, 1.0).unwrap();
+    let y_dist = Normal::new(1.0, 0.5).unwrap();
+    let x = (0..n).map(|_| x_dist.sample(&mut rng)).collect();
+    let y = (0..n).map(|_| y_dist.sample(&mut rng)).collect();
+    (x, y)
+}
+
+fn basic_histogram2d() {
+    let (x, y) = sample_normal_xy(5_000);
+    let trace = Histogram2d::new(x, y)
+        .n_bins_x(40)
+        .n_bins_y(40)
+        .hist_norm(HistNorm::Probability)
+        .color_scale(ColorScale::Palette(ColorScalePalette::Viridis));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn histogram2d_contour() {
+    let (x, y) = sample_normal_xy(5_000);
+    let trace = Histogram2dContour::new(x, y)
+        .n_contours(20)
+        .contours(Contours::new().coloring(ContoursColoring::HeatMap))
+        .color_scale(ColorScale::Palette(ColorScalePalette::Blues));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    basic_histogram2d();
+    histogram2d_contour();
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use plotly::common::{Anchor, AxisReference};
+use plotly::layout::{ImageSizing, Layer, LayoutImage};
+use plotly::{Bar, Layout, Plot, Scatter};
+use std::env;
+use std::fs;
+
+const LOGO_SVG: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="100">
+<circle cx="50" cy="50" r="45" fill="#636efa"/>
+<text x="50" y="62" font-size="36" text-anchor="middle" fill="white">rs</text>
+</svg>"##;
+
+fn logo_from_url() {
+    let trace = Bar::new(vec!["a", "b", "c"], vec![3, 5, 2]);
+    let layout = Layout::new().images(vec![LayoutImage::new()
+        .source("https://images.plot.ly/language-icons/api-home/python-logo.png")
+        .x_ref(AxisReference::Paper)
+        .y_ref(AxisReference::Paper)
+        .x(1.0)
+        .y(1.05)
+        .size_x(0.2)
+        .size_y(0.2)
+        .x_anchor(Anchor::Right)
+        .y_anchor(Anchor::Bottom)]);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn embedded_logo_from_file() -> Result<(), plotly::Error> {
+    let path = env::temp_dir().join("plotly_logo.svg");
+    fs::write(&path, LOGO_SVG)?;
+
+    let trace = Scatter::new(vec![0, 1, 2, 3], vec![1, 3, 2, 4]);
+    let layout = Layout::new().images(vec![LayoutImage::new()
+        .source_file(&path)?
+        .x_ref(AxisReference::Paper)
+        .y_ref(AxisReference::Paper)
+        .x(0.0)
+        .y(1.0)
+        .size_x(0.15)
+        .size_y(0.15)
+        .x_anchor(Anchor::Left)
+        .y_anchor(Anchor::Top)]);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    plot.show();
+    Ok(())
+}
+
+fn background_image() {
+    let trace = Scatter::new(
+        vec![0.0, 0.5, 1.0, 2.0, 2.2],
+        vec![1.23, 2.5, 0.42, 3.0, 1.0],
+    );
+    let layout = Layout::new().images(vec![LayoutImage::new()
+        .source_bytes(LOGO_SVG.as_bytes(), "image/svg+xml")
+        .x_ref(AxisReference::axis("x"))
+        .y_ref(AxisReference::axis("y"))
+        .x(0)
+        .y(3)
+        .size_x(2.0)
+        .size_y(2.0)
+        .sizing(ImageSizing::Stretch)
+        .opacity(0.5)
+        .layer(Layer::Below)]);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn main() -> Result<(), plotly::Error> {
+    logo_from_url();
+    embedded_logo_from_file()?;
+    background_image();
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use plotly::common::{Font, Marker, Side, Title};
+use plotly::layout::{Axis, GridPattern, LayoutGrid, RowOrder};
+use plotly::{Bar, Layout, NamedColor, Plot, Rgb, Scatter};
+
+fn two_y_axes() {
+    let trace1 = Scatter::new(vec![1, 2, 3], vec![40, 50, 60]).name("yaxis1 data");
+    let trace2 = Scatter::new(vec![2, 3, 4], vec![4, 5, 6])
+        .name("yaxis2 data")
+        .y_axis("y2");
+    let layout = Layout::new()
+        .title(Title::new("Double Y Axis Example"))
+        .yaxis(Axis::new().title(Title::new("yaxis title")))
+        .y_axis_n(
+            2,
+            Axis::new()
+                .title(Title::new("yaxis2 title").font(Font::new().color(Rgb::new(148, 103, 189))))
+                .tick_font(Font::new().color(Rgb::new(148, 103, 189)))
+                .overlaying("y")
+                .side(Side::Right),
+        );
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn subplots_with_shared_x_axis() {
+    let trace1 = Scatter::new(vec![0, 1, 2], vec![10, 11, 12]);
+    let trace2 = Bar::new(vec![0, 1, 2], vec![100, 110, 120])
+        .marker(Marker::new().color(NamedColor::Orange))
+        .x_axis("x2")
+        .y_axis("y2");
+    let layout = Layout::new()
+        .grid(
+            LayoutGrid::new()
+                .rows(2)
+                .columns(1)
+                .pattern(GridPattern::Independent)
+                .row_order(RowOrder::TopToBottom),
+        )
+        .x_axis_n(2, Axis::new().matches("x"));
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    two_y_axes();
+    subplots_with_shared_x_axis();
+    Ok(())
+}
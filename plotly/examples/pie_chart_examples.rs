@@ -0,0 +1,66 @@
+use plotly::common::{Domain, Line, TextPosition};
+use plotly::layout::{GridPattern, LayoutGrid};
+use plotly::pie::{InsideTextOrientation, PieInfo, PieMarker};
+use plotly::{Layout, NamedColor, Pie, Plot};
+
+fn basic_pie_chart() {
+    let trace = Pie::new(vec![4500, 2500, 1053, 500])
+        .labels(vec!["Oxygen", "Hydrogen", "Carbon Dioxide", "Nitrogen"])
+        .text_info(vec![PieInfo::Label, PieInfo::Percent])
+        .inside_text_orientation(InsideTextOrientation::Radial);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn donut_chart() {
+    let trace = Pie::new(vec![16, 15, 12, 6, 5, 4, 42])
+        .labels(vec![
+            "US",
+            "China",
+            "European Union",
+            "Russian Federation",
+            "Brazil",
+            "India",
+            "Rest of World",
+        ])
+        .hole(0.4)
+        .pull_array(vec![0.0, 0.1, 0.0, 0.0, 0.0, 0.0, 0.0])
+        .text_position(TextPosition::Inside)
+        .marker(PieMarker::new().line(Line::new().color(NamedColor::White).width(2.0)));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn pie_charts_in_grid() {
+    let labels = vec!["1st", "2nd", "3rd", "4th", "5th"];
+    let trace1 = Pie::new(vec![38, 27, 18, 10, 7])
+        .labels(labels.clone())
+        .name("Starry Night")
+        .domain(Domain::new().row(0).column(0));
+    let trace2 = Pie::new(vec![28, 26, 21, 15, 10])
+        .labels(labels)
+        .name("Sunflowers")
+        .sort(false)
+        .rotation(90.0)
+        .domain(Domain::new().row(0).column(1));
+    let layout = Layout::new().grid(
+        LayoutGrid::new()
+            .rows(1)
+            .columns(2)
+            .pattern(GridPattern::Independent),
+    );
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    basic_pie_chart();
+    donut_chart();
+    pie_charts_in_grid();
+    Ok(())
+}
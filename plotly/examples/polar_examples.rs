@@ -0,0 +1,103 @@
+use plotly::common::{Domain, Fill, Marker, Mode};
+use plotly::layout::{AngularAxis, LayoutPolar, PolarDirection, RadialAxis};
+use plotly::{BarPolar, Layout, NamedColor, Plot, ScatterPolar};
+
+fn radar_chart() {
+    let categories = vec![
+        "Speed",
+        "Reliability",
+        "Comfort",
+        "Safety",
+        "Efficiency",
+        "Speed",
+    ];
+    let trace1 = ScatterPolar::new(categories.clone(), vec![4, 3, 2, 5, 4, 4])
+        .name("Product A")
+        .fill(Fill::ToSelf);
+    let trace2 = ScatterPolar::new(categories, vec![3, 5, 4, 3, 2, 3])
+        .name("Product B")
+        .fill(Fill::ToSelf);
+    let layout = Layout::new().polar(
+        LayoutPolar::new().radial_axis(RadialAxis::new().visible(true).range(vec![0.0, 5.0])),
+    );
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn wind_rose_chart() {
+    let directions = vec!["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+    let trace1 = BarPolar::new(
+        directions.clone(),
+        vec![77.5, 72.5, 70.0, 45.0, 22.5, 42.5, 40.0, 62.5],
+    )
+    .name("11-14 m/s")
+    .marker(Marker::new().color(NamedColor::MediumPurple));
+    let trace2 = BarPolar::new(
+        directions.clone(),
+        vec![57.5, 50.0, 45.0, 35.0, 20.0, 22.5, 37.5, 55.0],
+    )
+    .name("8-11 m/s")
+    .marker(Marker::new().color(NamedColor::DarkViolet));
+    let trace3 = BarPolar::new(
+        directions,
+        vec![40.0, 30.0, 30.0, 35.0, 7.5, 7.5, 32.5, 40.0],
+    )
+    .name("5-8 m/s")
+    .marker(Marker::new().color(NamedColor::DeepPink));
+    let layout = Layout::new().polar(
+        LayoutPolar::new().bar_gap(0.1).angular_axis(
+            AngularAxis::new()
+                .direction(PolarDirection::Clockwise)
+                .rotation(90.0),
+        ),
+    );
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.add_trace(trace3);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn side_by_side_polar_subplots() {
+    let theta: Vec<f64> = (0..=360).step_by(5).map(|t| t as f64).collect();
+    let r1: Vec<f64> = theta
+        .iter()
+        .map(|t| (2.0 * t.to_radians()).cos().abs())
+        .collect();
+    let r2: Vec<f64> = theta
+        .iter()
+        .map(|t| 1.0 + (3.0 * t.to_radians()).sin() / 2.0)
+        .collect();
+
+    let trace1 = ScatterPolar::new(theta.clone(), r1)
+        .mode(Mode::Lines)
+        .name("rose");
+    let trace2 = ScatterPolar::new(theta, r2)
+        .mode(Mode::Lines)
+        .name("cardioid")
+        .subplot("polar2");
+    let layout = Layout::new()
+        .polar(LayoutPolar::new().domain(Domain::new().x(vec![0.0, 0.45])))
+        .polar_n(
+            2,
+            LayoutPolar::new()
+                .domain(Domain::new().x(vec![0.55, 1.0]))
+                .hole(0.2),
+        );
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    radar_chart();
+    wind_rose_chart();
+    side_by_side_polar_subplots();
+    Ok(())
+}
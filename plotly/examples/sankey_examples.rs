@@ -0,0 +1,62 @@
+use plotly::common::{Font, Line, Orientation};
+use plotly::sankey::{Arrangement, SankeyLink, SankeyNode};
+use plotly::{NamedColor, Plot, Rgba, Sankey};
+
+fn basic_sankey_diagram() {
+    let trace = Sankey::new()
+        .orientation(Orientation::Horizontal)
+        .node(
+            SankeyNode::new()
+                .pad(15.0)
+                .thickness(30.0)
+                .line(Line::new().color(NamedColor::Black).width(0.5))
+                .label(vec!["A1", "A2", "B1", "B2", "C1", "C2"])
+                .color_array(vec![
+                    NamedColor::Blue,
+                    NamedColor::Blue,
+                    NamedColor::Blue,
+                    NamedColor::Blue,
+                    NamedColor::Blue,
+                    NamedColor::Blue,
+                ]),
+        )
+        .link(
+            SankeyLink::new()
+                .source(vec![0, 1, 0, 2, 3, 3])
+                .target(vec![2, 3, 3, 4, 4, 5])
+                .value(vec![8.0, 4.0, 2.0, 8.0, 4.0, 2.0]),
+        );
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn pipeline_sankey_diagram() {
+    let trace = Sankey::new()
+        .arrangement(Arrangement::Snap)
+        .value_suffix(" jobs")
+        .text_font(Font::new().size(12))
+        .node(
+            SankeyNode::new()
+                .label(vec!["Queued", "Built", "Failed", "Tested", "Deployed"])
+                .x(vec![0.01, 0.3, 0.3, 0.6, 0.99])
+                .y(vec![0.5, 0.4, 0.9, 0.4, 0.4]),
+        )
+        .link(
+            SankeyLink::new()
+                .source(vec![0, 0, 1, 1, 3])
+                .target(vec![1, 2, 3, 2, 4])
+                .value(vec![90.0, 10.0, 80.0, 10.0, 75.0])
+                .color(Rgba::new(0, 0, 96, 0.2))
+                .hover_template("%{source.label} → %{target.label}: %{value}"),
+        );
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    basic_sankey_diagram();
+    pipeline_sankey_diagram();
+    Ok(())
+}
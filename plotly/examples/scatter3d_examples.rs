@@ -0,0 +1,47 @@
+use plotly::common::{Line, Marker, Mode};
+use plotly::scatter3d::{Projection, ProjectionCoord};
+use plotly::{NamedColor, Plot, Scatter3D};
+
+fn helix_scatter3d_plot() {
+    let n = 200;
+    let mut x: Vec<f64> = Vec::new();
+    let mut y: Vec<f64> = Vec::new();
+    let mut z: Vec<f64> = Vec::new();
+
+    for i in 0..n {
+        let t = i as f64 / 10.0;
+        x.push(t.cos());
+        y.push(t.sin());
+        z.push(t);
+    }
+
+    let trace = Scatter3D::new(x, y, z)
+        .name("helix")
+        .mode(Mode::LinesMarkers)
+        .marker(Marker::new().size(4).color(NamedColor::DarkOrange))
+        .line(Line::new().width(2.0).color(NamedColor::SteelBlue))
+        .projection(Projection::new().z(ProjectionCoord::new().show(true).opacity(0.5)));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn random_points_scatter3d_plot() {
+    let n = 300;
+    let x: Vec<f64> = (0..n).map(|_| rand::random::<f64>()).collect();
+    let y: Vec<f64> = (0..n).map(|_| rand::random::<f64>()).collect();
+    let z: Vec<f64> = (0..n).map(|_| rand::random::<f64>()).collect();
+
+    let trace = Scatter3D::new(x, y, z)
+        .mode(Mode::Markers)
+        .marker(Marker::new().size(3).opacity(0.8));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    helix_scatter3d_plot();
+    random_points_scatter3d_plot();
+    Ok(())
+}
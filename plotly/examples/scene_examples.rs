@@ -0,0 +1,78 @@
+use plotly::common::{Domain, Marker, Mode, Title};
+use plotly::layout::{AspectMode, Axis, Camera, CameraCenter, DragMode3D, Eye, LayoutScene, Up};
+use plotly::{Layout, Plot, Scatter3D, Surface};
+
+fn surface_with_camera() {
+    let n = 50;
+    let z: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    let x = i as f64 / n as f64 * 4.0 - 2.0;
+                    let y = j as f64 / n as f64 * 4.0 - 2.0;
+                    (-(x * x + y * y)).exp()
+                })
+                .collect()
+        })
+        .collect();
+    let trace = Surface::<f64, f64, f64>::new(z);
+    let layout = Layout::new().scene(
+        LayoutScene::new()
+            .xaxis(Axis::new().title(Title::new("x")))
+            .yaxis(Axis::new().title(Title::new("y")))
+            .zaxis(
+                Axis::new()
+                    .title(Title::new("height"))
+                    .range(vec![0.0, 1.0]),
+            )
+            .camera(
+                Camera::new()
+                    .eye(Eye::new(1.5, -1.5, 0.8))
+                    .center(CameraCenter::new(0.0, 0.0, -0.2))
+                    .up(Up::new(0.0, 0.0, 1.0)),
+            )
+            .aspect_mode(AspectMode::Cube)
+            .drag_mode(DragMode3D::Turntable),
+    );
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn side_by_side_scenes() {
+    let t: Vec<f64> = (0..100).map(|i| i as f64 / 10.0).collect();
+    let trace1 = Scatter3D::new(
+        t.iter().map(|t| t.cos()).collect(),
+        t.iter().map(|t| t.sin()).collect(),
+        t.clone(),
+    )
+    .mode(Mode::Lines)
+    .name("helix");
+    let trace2 = Scatter3D::new(
+        t.iter().map(|t| t * t.cos()).collect(),
+        t.iter().map(|t| t * t.sin()).collect(),
+        t.clone(),
+    )
+    .mode(Mode::Markers)
+    .marker(Marker::new().size(3))
+    .name("spiral")
+    .scene("scene2");
+    let layout = Layout::new()
+        .scene(LayoutScene::new().domain(Domain::new().x(vec![0.0, 0.5])))
+        .scene_n(
+            2,
+            LayoutScene::new().domain(Domain::new().x(vec![0.5, 1.0])),
+        );
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    surface_with_camera();
+    side_by_side_scenes();
+    Ok(())
+}
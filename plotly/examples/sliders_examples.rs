@@ -0,0 +1,49 @@
+use plotly::animation::{Easing, Transition};
+use plotly::common::{Font, Mode, Title};
+use plotly::layout::{CurrentValue, Restyle, Slider, SliderStep};
+use plotly::{Layout, Plot, Scatter};
+
+fn time_step_slider() {
+    // One trace per simulation time step, of which only the selected one is visible.
+    let n_steps = 20;
+    let x: Vec<f64> = (0..100).map(|i| i as f64 / 10.0).collect();
+    let mut plot = Plot::new();
+    let mut steps = Vec::with_capacity(n_steps);
+    for step in 0..n_steps {
+        let t = step as f64 / 4.0;
+        let y = x.iter().map(|x| (x - t).sin() * (-t / 5.0).exp()).collect();
+        plot.add_trace(
+            Scatter::new(x.clone(), y)
+                .mode(Mode::Lines)
+                .name(&format!("t = {}", t))
+                .visible(step == 0),
+        );
+
+        let visible: Vec<bool> = (0..n_steps).map(|i| i == step).collect();
+        steps.push(
+            SliderStep::new()
+                .label(&format!("{:.2}", t))
+                .restyle(Restyle::new().set_per_trace("visible", visible)),
+        );
+    }
+
+    let slider = Slider::new()
+        .active(0)
+        .steps(steps)
+        .current_value(
+            CurrentValue::new()
+                .prefix("t = ")
+                .font(Font::new().size(16)),
+        )
+        .transition(Transition::new().duration(300.0).easing(Easing::CubicInOut));
+    let layout = Layout::new()
+        .title(Title::new("Damped wave"))
+        .sliders(vec![slider]);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    time_step_slider();
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use plotly::common::{Mode, Title};
+use plotly::subplots::{SubplotSpec, SubplotType};
+use plotly::{Bar, Layout, Pie, Scatter, Scatter3D, Subplots};
+
+fn simple_subplot_grid() {
+    let mut plot = Subplots::new(2, 2)
+        .layout(Layout::new().title(Title::new("Subplot Grid")))
+        .build();
+    plot.add_trace_at(Scatter::new(vec![1, 2, 3], vec![4, 5, 6]), 1, 1);
+    plot.add_trace_at(Scatter::new(vec![20, 30, 40], vec![50, 60, 70]), 1, 2);
+    plot.add_trace_at(Bar::new(vec![1, 2, 3], vec![2, 1, 3]), 2, 1);
+    plot.add_trace_at(
+        Scatter::new(vec![4000, 5000, 6000], vec![7000, 8000, 9000]),
+        2,
+        2,
+    );
+    plot.show();
+}
+
+fn shared_axes_with_row_heights() {
+    let mut plot = Subplots::new(3, 1)
+        .shared_xaxes(true)
+        .row_heights(vec![2.0, 1.0, 1.0])
+        .vertical_spacing(0.06)
+        .build();
+    plot.add_trace_at(Scatter::new(vec![0, 1, 2], vec![10, 11, 12]), 1, 1);
+    plot.add_trace_at(Scatter::new(vec![2, 3, 4], vec![100, 110, 120]), 2, 1);
+    plot.add_trace_at(Scatter::new(vec![3, 4, 5], vec![1000, 1100, 1200]), 3, 1);
+    plot.show();
+}
+
+fn secondary_y_axis() {
+    let mut plot = Subplots::new(1, 2)
+        .specs(vec![vec![
+            SubplotSpec::new(SubplotType::XY).secondary_y(true),
+            SubplotSpec::new(SubplotType::XY).secondary_y(true),
+        ]])
+        .build();
+    plot.add_trace_at(
+        Scatter::new(vec![1, 2, 3], vec![2, 52, 62]).name("yaxis data"),
+        1,
+        1,
+    );
+    plot.add_secondary_y_trace_at(
+        Scatter::new(vec![1, 2, 3], vec![40, 50, 60]).name("yaxis2 data"),
+        1,
+        1,
+    );
+    plot.add_trace_at(
+        Scatter::new(vec![1, 2, 3], vec![2, 52, 62]).name("yaxis3 data"),
+        1,
+        2,
+    );
+    plot.add_secondary_y_trace_at(
+        Scatter::new(vec![1, 2, 3], vec![40, 50, 60]).name("yaxis4 data"),
+        1,
+        2,
+    );
+    plot.show();
+}
+
+fn mixed_subplot_types() {
+    let mut plot = Subplots::new(1, 3)
+        .specs(vec![vec![
+            SubplotSpec::new(SubplotType::XY),
+            SubplotSpec::new(SubplotType::Scene),
+            SubplotSpec::new(SubplotType::Domain),
+        ]])
+        .build();
+    plot.add_trace_at(Bar::new(vec!["a", "b", "c"], vec![2, 3, 1]), 1, 1);
+    plot.add_trace_at(
+        Scatter3D::new(vec![1, 2, 3], vec![3, 1, 2], vec![2, 3, 1]).mode(Mode::Markers),
+        1,
+        2,
+    );
+    plot.add_trace_at(Pie::new(vec![2, 3, 1]).labels(vec!["a", "b", "c"]), 1, 3);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    simple_subplot_grid();
+    shared_axes_with_row_heights();
+    secondary_y_axis();
+    mixed_subplot_types();
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use plotly::common::{Font, Marker, Mode, Title};
+use plotly::template::Theme;
+use plotly::{Bar, Layout, Plot, Scatter, Template};
+
+fn themed_plot(theme: Theme, title: &str) {
+    let trace1 = Scatter::new(vec![1, 2, 3, 4], vec![10, 15, 13, 17])
+        .name("trace1")
+        .mode(Mode::LinesMarkers);
+    let trace2 = Bar::new(vec![1, 2, 3, 4], vec![16, 5, 11, 9]).name("trace2");
+    let layout = Layout::new()
+        .title(Title::new(title))
+        .template(Template::builtin(theme));
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn builtin_themes() {
+    themed_plot(Theme::Plotly, "plotly");
+    themed_plot(Theme::PlotlyWhite, "plotly_white");
+    themed_plot(Theme::PlotlyDark, "plotly_dark");
+    themed_plot(Theme::Ggplot2, "ggplot2");
+    themed_plot(Theme::Seaborn, "seaborn");
+    themed_plot(Theme::SimpleWhite, "simple_white");
+}
+
+fn merged_template() {
+    let company = Template::new()
+        .layout(
+            Layout::new()
+                .font(Font::new().family("Courier New, monospace"))
+                .colorway(vec!["#003f5c", "#bc5090", "#ffa600"]),
+        )
+        .add_data(
+            Scatter::new(Vec::<f64>::new(), Vec::<f64>::new())
+                .mode(Mode::LinesMarkers)
+                .marker(Marker::new().size(10)),
+        );
+    let template = Template::builtin(Theme::PlotlyWhite)
+        .merge(Template::builtin(Theme::Presentation))
+        .merge(company);
+
+    let mut plot = Plot::new();
+    plot.add_trace(Scatter::new(vec![1, 2, 3], vec![2, 1, 3]).name("a"));
+    plot.add_trace(Scatter::new(vec![1, 2, 3], vec![3, 2, 1]).name("b"));
+    plot.set_layout(
+        Layout::new()
+            .title(Title::new("plotly_white+presentation+company"))
+            .template(template),
+    );
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    builtin_themes();
+    merged_template();
+    Ok(())
+}
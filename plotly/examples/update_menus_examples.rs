@@ -0,0 +1,88 @@
+use plotly::common::{Anchor, Title};
+use plotly::layout::{
+    BarMode, Button, Relayout, Restyle, UpdateMenu, UpdateMenuDirection, UpdateMenuType,
+};
+use plotly::{Bar, HeatMap, Layout, Plot, Scatter};
+
+fn toggle_traces_with_buttons() {
+    let trace1 = Scatter::new(vec![1, 2, 3, 4], vec![10, 15, 13, 17]).name("High");
+    let trace2 = Scatter::new(vec![1, 2, 3, 4], vec![16, 5, 11, 9]).name("Low");
+    let buttons = vec![
+        Button::new().label("Both").update(
+            Restyle::new().set("visible", true),
+            Relayout::new().set("title.text", "High and Low"),
+        ),
+        Button::new().label("High").update(
+            Restyle::new().set_per_trace("visible", vec![true, false]),
+            Relayout::new().set("title.text", "High"),
+        ),
+        Button::new().label("Low").update(
+            Restyle::new().set_per_trace("visible", vec![false, true]),
+            Relayout::new().set("title.text", "Low"),
+        ),
+    ];
+    let layout = Layout::new()
+        .title(Title::new("High and Low"))
+        .update_menus(vec![UpdateMenu::new()
+            .menu_type(UpdateMenuType::Buttons)
+            .direction(UpdateMenuDirection::Right)
+            .buttons(buttons)
+            .x(0.0)
+            .x_anchor(Anchor::Left)
+            .y(1.15)
+            .y_anchor(Anchor::Top)]);
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn restyle_with_dropdown() {
+    let trace = HeatMap::new_z(vec![vec![1, 20, 30], vec![20, 1, 60], vec![30, 60, 1]]);
+    let buttons = vec![
+        Button::new()
+            .label("Viridis")
+            .restyle(Restyle::new().set("colorscale", "Viridis")),
+        Button::new()
+            .label("Cividis")
+            .restyle(Restyle::new().set("colorscale", "Cividis")),
+        Button::new()
+            .label("Blues")
+            .restyle(Restyle::new().set("colorscale", "Blues")),
+    ];
+    let layout = Layout::new().update_menus(vec![UpdateMenu::new()
+        .menu_type(UpdateMenuType::Dropdown)
+        .buttons(buttons)
+        .y(1.15)]);
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn relayout_bar_mode() {
+    let trace1 = Bar::new(vec!["a", "b", "c"], vec![20, 14, 23]).name("2019");
+    let trace2 = Bar::new(vec!["a", "b", "c"], vec![12, 18, 29]).name("2020");
+    let buttons = vec![
+        Button::new()
+            .label("Grouped")
+            .relayout(Relayout::new().set("barmode", BarMode::Group)),
+        Button::new()
+            .label("Stacked")
+            .relayout(Relayout::new().set("barmode", BarMode::Stack)),
+    ];
+    let layout = Layout::new().update_menus(vec![UpdateMenu::new().buttons(buttons).y(1.15)]);
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    toggle_traces_with_buttons();
+    restyle_with_dropdown();
+    relayout_bar_mode();
+    Ok(())
+}
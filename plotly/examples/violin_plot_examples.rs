@@ -0,0 +1,70 @@
+use plotly::box_plot::BoxPoints;
+use plotly::layout::ViolinMode;
+use plotly::violin::{MeanLine, ViolinBox, ViolinSide};
+use plotly::{Layout, NamedColor, Plot, Violin};
+use rand_distr::{Distribution, Normal};
+
+fn sample_normal(mean: f64, std_dev: f64, n: usize) -> Vec<f64> {
+    let mut rng = rand::thread_rng();
+    let dist = Normal::new(mean, std_dev).unwrap();
+    (0..n).map(|_| dist.sample(&mut rng)).collect()
+}
+
+fn basic_violin_plot() {
+    let trace = Violin::new(sample_normal(0.0, 1.0, 500))
+        .name("Sample")
+        .points(BoxPoints::All)
+        .jitter(0.05)
+        .point_pos(-1.5)
+        .inner_box(ViolinBox::new().visible(true))
+        .mean_line(MeanLine::new().visible(true));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn grouped_violin_plot() {
+    let x = vec!["Mon"; 200]
+        .into_iter()
+        .chain(vec!["Tue"; 200])
+        .collect::<Vec<&str>>();
+
+    let trace1 = Violin::new_xy(x.clone(), sample_normal(10.0, 2.0, 400))
+        .name("Lunch")
+        .fill_color(NamedColor::LightSeaGreen);
+    let trace2 = Violin::new_xy(x, sample_normal(14.0, 3.0, 400))
+        .name("Dinner")
+        .fill_color(NamedColor::MediumPurple);
+    let layout = Layout::new().violin_mode(ViolinMode::Group);
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn split_violin_plot() {
+    let trace1 = Violin::new(sample_normal(0.0, 1.0, 300))
+        .name("Before")
+        .side(ViolinSide::Negative)
+        .scale_group("split");
+    let trace2 = Violin::new(sample_normal(0.5, 1.2, 300))
+        .name("After")
+        .side(ViolinSide::Positive)
+        .scale_group("split");
+    let layout = Layout::new()
+        .violin_gap(0.0)
+        .violin_mode(ViolinMode::Overlay);
+    let mut plot = Plot::new();
+    plot.add_trace(trace1);
+    plot.add_trace(trace2);
+    plot.set_layout(layout);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    basic_violin_plot();
+    grouped_violin_plot();
+    split_violin_plot();
+    Ok(())
+}
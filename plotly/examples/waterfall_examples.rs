@@ -0,0 +1,61 @@
+use plotly::common::{Line, Marker, Orientation, TextPosition};
+use plotly::waterfall::{BarStyle, Connector, ConnectorMode, Measure};
+use plotly::{NamedColor, Plot, Waterfall};
+
+fn basic_waterfall_chart() {
+    let trace = Waterfall::new(
+        vec![
+            "Sales",
+            "Consulting",
+            "Net revenue",
+            "Purchases",
+            "Other expenses",
+            "Profit before tax",
+        ],
+        vec![60, 80, 0, -40, -20, 0],
+    )
+    .measure(vec![
+        Measure::Relative,
+        Measure::Relative,
+        Measure::Total,
+        Measure::Relative,
+        Measure::Relative,
+        Measure::Total,
+    ])
+    .text_position(TextPosition::Outside)
+    .connector(Connector::new().line(Line::new().color(NamedColor::DimGray)));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn styled_horizontal_waterfall_chart() {
+    let trace = Waterfall::new(
+        vec![375, 128, 78, 27, -327, -12, -78, -12],
+        vec![
+            "Sales",
+            "Consulting",
+            "Maintenance",
+            "Other revenue",
+            "Rent",
+            "Salaries",
+            "Utilities",
+            "Taxes",
+        ],
+    )
+    .orientation(Orientation::Horizontal)
+    .base(1000.0)
+    .connector(Connector::new().mode(ConnectorMode::Between))
+    .increasing(BarStyle::new().marker(Marker::new().color(NamedColor::SeaGreen)))
+    .decreasing(BarStyle::new().marker(Marker::new().color(NamedColor::IndianRed)))
+    .totals(BarStyle::new().marker(Marker::new().color(NamedColor::SteelBlue)));
+    let mut plot = Plot::new();
+    plot.add_trace(trace);
+    plot.show();
+}
+
+fn main() -> std::io::Result<()> {
+    basic_waterfall_chart();
+    styled_horizontal_waterfall_chart();
+    Ok(())
+}
@@ -0,0 +1,303 @@
+//! Animation frames, and the options shared by `Plotly.animate` buttons, sliders and animated
+//! plots.
+
+use crate::{Layout, Trace};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Easing {
+    #[serde(rename = "linear")]
+    Linear,
+    #[serde(rename = "linear-in")]
+    LinearIn,
+    #[serde(rename = "linear-out")]
+    LinearOut,
+    #[serde(rename = "linear-in-out")]
+    LinearInOut,
+    #[serde(rename = "quad")]
+    Quad,
+    #[serde(rename = "quad-in")]
+    QuadIn,
+    #[serde(rename = "quad-out")]
+    QuadOut,
+    #[serde(rename = "quad-in-out")]
+    QuadInOut,
+    #[serde(rename = "cubic")]
+    Cubic,
+    #[serde(rename = "cubic-in")]
+    CubicIn,
+    #[serde(rename = "cubic-out")]
+    CubicOut,
+    #[serde(rename = "cubic-in-out")]
+    CubicInOut,
+    #[serde(rename = "sin")]
+    Sin,
+    #[serde(rename = "sin-in")]
+    SinIn,
+    #[serde(rename = "sin-out")]
+    SinOut,
+    #[serde(rename = "sin-in-out")]
+    SinInOut,
+    #[serde(rename = "exp")]
+    Exp,
+    #[serde(rename = "exp-in")]
+    ExpIn,
+    #[serde(rename = "exp-out")]
+    ExpOut,
+    #[serde(rename = "exp-in-out")]
+    ExpInOut,
+    #[serde(rename = "circle")]
+    Circle,
+    #[serde(rename = "circle-in")]
+    CircleIn,
+    #[serde(rename = "circle-out")]
+    CircleOut,
+    #[serde(rename = "circle-in-out")]
+    CircleInOut,
+    #[serde(rename = "elastic")]
+    Elastic,
+    #[serde(rename = "elastic-in")]
+    ElasticIn,
+    #[serde(rename = "elastic-out")]
+    ElasticOut,
+    #[serde(rename = "elastic-in-out")]
+    ElasticInOut,
+    #[serde(rename = "back")]
+    Back,
+    #[serde(rename = "back-in")]
+    BackIn,
+    #[serde(rename = "back-out")]
+    BackOut,
+    #[serde(rename = "back-in-out")]
+    BackInOut,
+    #[serde(rename = "bounce")]
+    Bounce,
+    #[serde(rename = "bounce-in")]
+    BounceIn,
+    #[serde(rename = "bounce-out")]
+    BounceOut,
+    #[serde(rename = "bounce-in-out")]
+    BounceInOut,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum TransitionOrdering {
+    #[serde(rename = "layout first")]
+    LayoutFirst,
+    #[serde(rename = "traces first")]
+    TracesFirst,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Transition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    easing: Option<Easing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ordering: Option<TransitionOrdering>,
+}
+
+impl Transition {
+    pub fn new() -> Transition {
+        Transition {
+            duration: None,
+            easing: None,
+            ordering: None,
+        }
+    }
+
+    pub fn duration(mut self, duration: f64) -> Transition {
+        self.duration = Some(duration);
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Transition {
+        self.easing = Some(easing);
+        self
+    }
+
+    pub fn ordering(mut self, ordering: TransitionOrdering) -> Transition {
+        self.ordering = Some(ordering);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum AnimationMode {
+    #[serde(rename = "immediate")]
+    Immediate,
+    #[serde(rename = "next")]
+    Next,
+    #[serde(rename = "afterall")]
+    AfterAll,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum AnimationDirection {
+    #[serde(rename = "forward")]
+    Forward,
+    #[serde(rename = "reverse")]
+    Reverse,
+}
+
+/// How long each frame is shown for, and whether the plot is fully redrawn for it, which is
+/// needed when a frame changes anything other than the data of scatter-like traces.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct FrameSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    duration: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redraw: Option<bool>,
+}
+
+impl FrameSettings {
+    pub fn new() -> FrameSettings {
+        FrameSettings {
+            duration: None,
+            redraw: None,
+        }
+    }
+
+    pub fn duration(mut self, duration: f64) -> FrameSettings {
+        self.duration = Some(duration);
+        self
+    }
+
+    pub fn redraw(mut self, redraw: bool) -> FrameSettings {
+        self.redraw = Some(redraw);
+        self
+    }
+}
+
+/// Options passed to `Plotly.animate`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct AnimationOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frame: Option<FrameSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transition: Option<Transition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<AnimationMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<AnimationDirection>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fromcurrent")]
+    from_current: Option<bool>,
+}
+
+impl AnimationOptions {
+    pub fn new() -> AnimationOptions {
+        AnimationOptions {
+            frame: None,
+            transition: None,
+            mode: None,
+            direction: None,
+            from_current: None,
+        }
+    }
+
+    pub fn frame(mut self, frame: FrameSettings) -> AnimationOptions {
+        self.frame = Some(frame);
+        self
+    }
+
+    pub fn transition(mut self, transition: Transition) -> AnimationOptions {
+        self.transition = Some(transition);
+        self
+    }
+
+    pub fn mode(mut self, mode: AnimationMode) -> AnimationOptions {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn direction(mut self, direction: AnimationDirection) -> AnimationOptions {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn from_current(mut self, from_current: bool) -> AnimationOptions {
+        self.from_current = Some(from_current);
+        self
+    }
+}
+
+/// A frame of an animated `Plot`, added with `Plot::add_frame`.
+///
+/// Animating to a frame updates the traces of the `Plot` with its data, by default starting from
+/// the first trace, and the layout with its `Layout`. Frames are played by `Animate` buttons and
+/// slider steps, or on load with `Plot::set_animation_options`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Frame {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "baseframe")]
+    base_frame: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    traces: Option<Vec<usize>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
+}
+
+impl Frame {
+    pub fn new() -> Frame {
+        Frame {
+            name: None,
+            group: None,
+            base_frame: None,
+            traces: None,
+            data: None,
+            layout: None,
+        }
+    }
+
+    pub fn name(mut self, name: &str) -> Frame {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    pub fn group(mut self, group: &str) -> Frame {
+        self.group = Some(group.to_owned());
+        self
+    }
+
+    /// The name of a frame whose data and layout this frame starts from.
+    pub fn base_frame(mut self, base_frame: &str) -> Frame {
+        self.base_frame = Some(base_frame.to_owned());
+        self
+    }
+
+    /// The indices of the `Plot` traces updated by the data of this frame, in the same order.
+    pub fn traces(mut self, traces: Vec<usize>) -> Frame {
+        self.traces = Some(traces);
+        self
+    }
+
+    pub fn data(mut self, data: Vec<Box<dyn Trace>>) -> Frame {
+        let data = data
+            .iter()
+            .map(|trace| serde_json::from_str(&trace.serialize()).unwrap())
+            .collect();
+        self.data = Some(data);
+        self
+    }
+
+    pub fn add_trace(mut self, trace: Box<dyn Trace>) -> Frame {
+        let trace = serde_json::from_str(&trace.serialize()).unwrap();
+        self.data.get_or_insert_with(Vec::new).push(trace);
+        self
+    }
+
+    pub fn layout(mut self, layout: Layout) -> Frame {
+        self.layout = Some(layout);
+        self
+    }
+}
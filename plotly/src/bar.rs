@@ -2,18 +2,19 @@
 
 use crate::common::{
     Calendar, ConstrainText, Dim, ErrorData, Font, HoverInfo, Label, Marker, Orientation, PlotType,
-    TextAnchor, TextPosition,
+    TextAnchor, TextPosition, Visible,
 };
 use crate::Trace;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::private;
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Bar<X, Y>
 where
     X: Serialize,
-    Y: num::Num + Serialize,
+    Y: Serialize,
 {
     x: Vec<X>,
     y: Vec<Y>,
@@ -21,7 +22,11 @@ where
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    visible: Option<bool>,
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
     show_legend: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
@@ -78,12 +83,16 @@ where
     x_calendar: Option<Calendar>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<String>,
 }
 
 impl<X, Y> Bar<X, Y>
 where
     X: Serialize,
-    Y: num::Num + Serialize,
+    Y: Serialize,
 {
     pub fn new(x: Vec<X>, y: Vec<Y>) -> Box<Bar<X, Y>> {
         Box::new(Bar {
@@ -91,6 +100,8 @@ where
             y,
             r#type: PlotType::Bar,
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -120,6 +131,8 @@ where
             outside_text_font: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 
@@ -128,8 +141,18 @@ where
         Box::new(self)
     }
 
-    pub fn visible(mut self, visible: bool) -> Box<Bar<X, Y>> {
-        self.visible = Some(visible);
+    pub fn uid(mut self, uid: &str) -> Box<Bar<X, Y>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Bar<X, Y>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Bar<X, Y>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
         Box::new(self)
     }
 
@@ -307,14 +330,38 @@ where
         self.y_calendar = Some(y_calendar);
         Box::new(self)
     }
+
+    pub fn x_axis(mut self, x_axis: &str) -> Box<Bar<X, Y>> {
+        self.x_axis = Some(x_axis.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, y_axis: &str) -> Box<Bar<X, Y>> {
+        self.y_axis = Some(y_axis.to_owned());
+        Box::new(self)
+    }
 }
 
 impl<X, Y> Trace for Bar<X, Y>
 where
     X: Serialize,
-    Y: num::Num + Serialize,
+    Y: Serialize,
 {
     fn serialize(&self) -> String {
         serde_json::to_string(&self).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_bar_axes() {
+        let trace = Bar::new(vec!["a", "b"], vec![1, 2])
+            .x_axis("x2")
+            .y_axis("y2");
+        let expected = r#"{"x":["a","b"],"y":[1,2],"type":"bar","xaxis":"x2","yaxis":"y2"}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,251 @@
+//! Bar plot in polar coordinates
+
+use crate::common::{Dim, Label, Marker, PlotType, PolarHoverInfo, ThetaUnit, Visible};
+use crate::private;
+use crate::Trace;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct BarPolar<Theta, R>
+where
+    Theta: Serialize,
+    R: Serialize,
+{
+    r#type: PlotType,
+    theta: Vec<Theta>,
+    r: Vec<R>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base: Option<Dim<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<Dim<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<Dim<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "thetaunit")]
+    theta_unit: Option<ThetaUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<private::FlagList<PolarHoverInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subplot: Option<String>,
+}
+
+impl<Theta, R> BarPolar<Theta, R>
+where
+    Theta: Serialize,
+    R: Serialize,
+{
+    pub fn new(theta: Vec<Theta>, r: Vec<R>) -> Box<BarPolar<Theta, R>> {
+        Box::new(BarPolar {
+            r#type: PlotType::BarPolar,
+            theta,
+            r,
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            ids: None,
+            base: None,
+            offset: None,
+            width: None,
+            theta_unit: None,
+            text: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            marker: None,
+            hover_label: None,
+            subplot: None,
+        })
+    }
+
+    pub fn name(mut self, name: &str) -> Box<BarPolar<Theta, R>> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<BarPolar<Theta, R>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<BarPolar<Theta, R>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<BarPolar<Theta, R>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<BarPolar<Theta, R>> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<BarPolar<Theta, R>> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<BarPolar<Theta, R>> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<BarPolar<Theta, R>> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn base(mut self, base: f64) -> Box<BarPolar<Theta, R>> {
+        self.base = Some(Dim::Scalar(base));
+        Box::new(self)
+    }
+
+    pub fn base_array(mut self, base: Vec<f64>) -> Box<BarPolar<Theta, R>> {
+        self.base = Some(Dim::Vector(base));
+        Box::new(self)
+    }
+
+    pub fn offset(mut self, offset: f64) -> Box<BarPolar<Theta, R>> {
+        self.offset = Some(Dim::Scalar(offset));
+        Box::new(self)
+    }
+
+    pub fn offset_array(mut self, offset: Vec<f64>) -> Box<BarPolar<Theta, R>> {
+        self.offset = Some(Dim::Vector(offset));
+        Box::new(self)
+    }
+
+    pub fn width(mut self, width: f64) -> Box<BarPolar<Theta, R>> {
+        self.width = Some(Dim::Scalar(width));
+        Box::new(self)
+    }
+
+    pub fn width_array(mut self, width: Vec<f64>) -> Box<BarPolar<Theta, R>> {
+        self.width = Some(Dim::Vector(width));
+        Box::new(self)
+    }
+
+    pub fn theta_unit(mut self, theta_unit: ThetaUnit) -> Box<BarPolar<Theta, R>> {
+        self.theta_unit = Some(theta_unit);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<BarPolar<Theta, R>> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<BarPolar<Theta, R>> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<BarPolar<Theta, R>> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(
+        mut self,
+        hover_text: Vec<S>,
+    ) -> Box<BarPolar<Theta, R>> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: Vec<PolarHoverInfo>) -> Box<BarPolar<Theta, R>> {
+        self.hover_info = Some(private::FlagList { flags: hover_info });
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<BarPolar<Theta, R>> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(
+        mut self,
+        hover_template: Vec<S>,
+    ) -> Box<BarPolar<Theta, R>> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Box<BarPolar<Theta, R>> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<BarPolar<Theta, R>> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn subplot(mut self, subplot: &str) -> Box<BarPolar<Theta, R>> {
+        self.subplot = Some(subplot.to_owned());
+        Box::new(self)
+    }
+}
+
+impl<Theta, R> Trace for BarPolar<Theta, R>
+where
+    Theta: Serialize,
+    R: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_bar_polar() {
+        let trace = BarPolar::new(vec!["N", "E", "S", "W"], vec![4, 2, 3, 1])
+            .base(0.5)
+            .width(45.0)
+            .theta_unit(ThetaUnit::Radians)
+            .hover_info(vec![PolarHoverInfo::Skip])
+            .subplot("polar");
+        let expected = r#"{"type":"barpolar","theta":["N","E","S","W"],"r":[4,2,3,1],"base":0.5,"width":45.0,"thetaunit":"radians","hoverinfo":"skip","subplot":"polar"}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
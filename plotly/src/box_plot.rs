@@ -1,12 +1,14 @@
 //! Box plot
 
 use crate::common::color::Color;
-use crate::common::{Calendar, Dim, HoverInfo, Label, Line, Marker, Orientation, PlotType};
+use crate::common::{
+    Calendar, Dim, HoverInfo, Label, Line, Marker, Orientation, PlotType, Visible,
+};
 use crate::private;
 use crate::Trace;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum BoxMean {
     #[serde(rename = "true")]
     True,
@@ -16,7 +18,7 @@ pub enum BoxMean {
     StandardDeviation,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum BoxPoints {
     #[serde(rename = "all")]
     All,
@@ -28,7 +30,7 @@ pub enum BoxPoints {
     False,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum QuartileMethod {
     #[serde(rename = "linear")]
     Linear,
@@ -38,7 +40,8 @@ pub enum QuartileMethod {
     Inclusive,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct BoxPlot<Y, X>
 where
     Y: Serialize,
@@ -52,7 +55,11 @@ where
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    visible: Option<bool>,
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
     show_legend: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
@@ -121,6 +128,10 @@ where
     x_calendar: Option<Calendar>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<String>,
 }
 
 impl<Y> BoxPlot<Y, f64>
@@ -133,6 +144,8 @@ where
             x: None,
             y: Some(y),
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -168,6 +181,8 @@ where
             jitter: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 }
@@ -183,6 +198,8 @@ where
             x: Some(x),
             y: Some(y),
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -218,6 +235,8 @@ where
             jitter: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 
@@ -227,6 +246,8 @@ where
             x: Some(x),
             y: None,
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -262,6 +283,8 @@ where
             jitter: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 
@@ -270,8 +293,18 @@ where
         Box::new(self)
     }
 
-    pub fn visible(mut self, visible: bool) -> Box<BoxPlot<Y, X>> {
-        self.visible = Some(visible);
+    pub fn uid(mut self, uid: &str) -> Box<BoxPlot<Y, X>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<BoxPlot<Y, X>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<BoxPlot<Y, X>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
         Box::new(self)
     }
 
@@ -466,6 +499,16 @@ where
         self.y_calendar = Some(y_calendar);
         Box::new(self)
     }
+
+    pub fn x_axis(mut self, x_axis: &str) -> Box<BoxPlot<Y, X>> {
+        self.x_axis = Some(x_axis.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, y_axis: &str) -> Box<BoxPlot<Y, X>> {
+        self.y_axis = Some(y_axis.to_owned());
+        Box::new(self)
+    }
 }
 
 impl<X, Y> Trace for BoxPlot<X, Y>
@@ -1,16 +1,17 @@
 //! Candlestick plot
 
 use crate::common::color::NamedColor;
-use crate::common::{Calendar, Dim, Direction, HoverInfo, Label, Line, PlotType};
+use crate::common::{Calendar, Dim, Direction, HoverInfo, Label, Line, PlotType, Visible};
 use crate::private;
 use crate::Trace;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Candlestick<T, O>
 where
     T: Serialize,
-    O: num::Num + Serialize,
+    O: Serialize,
 {
     r#type: PlotType,
     x: Vec<T>,
@@ -21,7 +22,11 @@ where
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    visible: Option<bool>,
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
     show_legend: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
@@ -46,12 +51,16 @@ where
     hover_label: Option<Label>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "xcalendar")]
     x_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<String>,
 }
 
 impl<T, O> Candlestick<T, O>
 where
     T: Serialize,
-    O: num::Num + Serialize,
+    O: Serialize,
 {
     pub fn new(
         x: Vec<T>,
@@ -70,6 +79,8 @@ where
             low,
             close,
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -83,6 +94,8 @@ where
             decreasing: Some(Direction::Decreasing { line: dline }),
             hover_label: None,
             x_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 
@@ -91,8 +104,18 @@ where
         Box::new(self)
     }
 
-    pub fn visible(mut self, visible: bool) -> Box<Candlestick<T, O>> {
-        self.visible = Some(visible);
+    pub fn uid(mut self, uid: &str) -> Box<Candlestick<T, O>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Candlestick<T, O>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Candlestick<T, O>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
         Box::new(self)
     }
 
@@ -167,12 +190,22 @@ where
         self.x_calendar = Some(x_calendar);
         Box::new(self)
     }
+
+    pub fn x_axis(mut self, x_axis: &str) -> Box<Candlestick<T, O>> {
+        self.x_axis = Some(x_axis.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, y_axis: &str) -> Box<Candlestick<T, O>> {
+        self.y_axis = Some(y_axis.to_owned());
+        Box::new(self)
+    }
 }
 
 impl<X, Y> Trace for Candlestick<X, Y>
 where
     X: Serialize,
-    Y: num::Num + Serialize,
+    Y: Serialize,
 {
     fn serialize(&self) -> String {
         serde_json::to_string(&self).unwrap()
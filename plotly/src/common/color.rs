@@ -196,7 +196,7 @@ pub enum NamedColor {
 
 impl Color for NamedColor {
     fn to_color_string(&self) -> String {
-        let color = match self {
+        match self {
             NamedColor::AliceBlue => "aliceblue".to_owned(),
             NamedColor::AntiqueWhite => "antiquewhite".to_owned(),
             NamedColor::Aqua => "aqua".to_owned(),
@@ -345,8 +345,7 @@ impl Color for NamedColor {
             NamedColor::Yellow => "yellow".to_owned(),
             NamedColor::YellowGreen => "yellowgreen".to_owned(),
             NamedColor::Transparent => "transparent".to_owned(),
-        };
-        color
+        }
     }
 }
 
@@ -362,13 +361,13 @@ where
 {
     fn to_color_string(&self) -> String {
         if self.as_ref().len() < 6 || self.as_ref().len() > 7 {
-            panic!(format!("{} is not a valid hex color!", self));
+            panic!("{} is not a valid hex color!", self);
         }
-        if self.as_ref().len() == 6 && self.as_ref().chars().next().unwrap() == '#' {
-            panic!(format!("{} is not a valid hex color!", self));
+        if self.as_ref().len() == 6 && self.as_ref().starts_with('#') {
+            panic!("{} is not a valid hex color!", self);
         }
-        if self.as_ref().len() == 7 && self.as_ref().chars().next().unwrap() != '#' {
-            panic!(format!("{} is not a valid hex color!", self));
+        if self.as_ref().len() == 7 && !self.as_ref().starts_with('#') {
+            panic!("{} is not a valid hex color!", self);
         }
         let valid_characters = "#ABCDEF0123456789";
         let mut s = self.as_ref().to_uppercase();
@@ -377,7 +376,7 @@ where
         }
         for c in s.chars() {
             if !valid_characters.contains(c) {
-                panic!(format!("{} is not a valid hex color!", self));
+                panic!("{} is not a valid hex color!", self);
             }
         }
 
@@ -402,11 +401,11 @@ mod tests {
     #[test]
     fn hex_color_normalization_string() {
         assert_eq!(String::from("#aabbcc").to_color_string(), "#AABBCC");
-        assert_eq!(String::from("aabbcc".to_color_string()), "#AABBCC");
-        assert_eq!(String::from("aaBBcc".to_color_string()), "#AABBCC");
-        assert_eq!(String::from("FABCDe".to_color_string()), "#FABCDE");
-        assert_eq!(String::from("123456".to_color_string()), "#123456");
-        assert_eq!(String::from("7890EE".to_color_string()), "#7890EE");
+        assert_eq!(String::from("aabbcc").to_color_string(), "#AABBCC");
+        assert_eq!(String::from("aaBBcc").to_color_string(), "#AABBCC");
+        assert_eq!(String::from("FABCDe").to_color_string(), "#FABCDE");
+        assert_eq!(String::from("123456").to_color_string(), "#123456");
+        assert_eq!(String::from("7890EE").to_color_string(), "#7890EE");
     }
 
     #[test]
@@ -0,0 +1,302 @@
+//! Types shared by the hierarchical `Sunburst` and `Treemap` traces, and helpers for building their
+//! `ids`, `labels` and `parents` arrays.
+
+use std::collections::HashMap;
+
+use crate::common::color::Color;
+use crate::common::{ColorBar, ColorScale, Line};
+use crate::private;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum BranchValues {
+    #[serde(rename = "remainder")]
+    Remainder,
+    #[serde(rename = "total")]
+    Total,
+}
+
+/// The information shown on the sectors of a `Sunburst` or `Treemap` with `text_info`, or on
+/// hover with `hover_info`. Several flags are combined, e.g.
+/// `vec![HierarchyInfo::Label, HierarchyInfo::PercentParent]`.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum HierarchyInfo {
+    #[serde(rename = "label")]
+    Label,
+    #[serde(rename = "text")]
+    Text,
+    #[serde(rename = "value")]
+    Value,
+    /// Only valid for `hover_info`.
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "current path")]
+    CurrentPath,
+    #[serde(rename = "percent root")]
+    PercentRoot,
+    #[serde(rename = "percent entry")]
+    PercentEntry,
+    #[serde(rename = "percent parent")]
+    PercentParent,
+    /// Only valid for `hover_info`.
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "none")]
+    None,
+    /// Only valid for `hover_info`.
+    #[serde(rename = "skip")]
+    Skip,
+}
+
+/// The nodes counted when no `values` are given to a `Sunburst` or `Treemap`, set with `count`.
+/// Both flags may be combined.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum HierarchyCount {
+    #[serde(rename = "branches")]
+    Branches,
+    #[serde(rename = "leaves")]
+    Leaves,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct HierarchyMarker {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    colors: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+}
+
+impl HierarchyMarker {
+    pub fn new() -> HierarchyMarker {
+        HierarchyMarker {
+            colors: None,
+            line: None,
+            color_scale: None,
+            show_scale: None,
+            reverse_scale: None,
+            color_bar: None,
+        }
+    }
+
+    pub fn colors<C: Color>(mut self, colors: Vec<C>) -> HierarchyMarker {
+        let colors = private::to_color_array(colors);
+        self.colors = Some(colors);
+        self
+    }
+
+    pub fn line(mut self, line: Line) -> HierarchyMarker {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn color_scale(mut self, color_scale: ColorScale) -> HierarchyMarker {
+        self.color_scale = Some(color_scale);
+        self
+    }
+
+    pub fn show_scale(mut self, show_scale: bool) -> HierarchyMarker {
+        self.show_scale = Some(show_scale);
+        self
+    }
+
+    pub fn reverse_scale(mut self, reverse_scale: bool) -> HierarchyMarker {
+        self.reverse_scale = Some(reverse_scale);
+        self
+    }
+
+    pub fn color_bar(mut self, color_bar: ColorBar) -> HierarchyMarker {
+        self.color_bar = Some(color_bar);
+        self
+    }
+}
+
+/// A node of a tree that can be flattened into a `Hierarchy` with `Hierarchy::from_tree`.
+#[derive(Debug)]
+pub struct Node {
+    label: String,
+    value: Option<f64>,
+    children: Vec<Node>,
+}
+
+impl Node {
+    pub fn new(label: &str) -> Node {
+        Node {
+            label: label.to_owned(),
+            value: None,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn value(mut self, value: f64) -> Node {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn child(mut self, child: Node) -> Node {
+        self.children.push(child);
+        self
+    }
+
+    pub fn children(mut self, children: Vec<Node>) -> Node {
+        self.children.extend(children);
+        self
+    }
+}
+
+/// The flattened `ids`, `labels`, `parents` and `values` arrays of a hierarchy, used to build
+/// `Sunburst` and `Treemap` traces with their `from_hierarchy` constructors.
+///
+/// The id of each node is the `/` separated path of labels leading to it from its root, with `/`
+/// and `\` escaped inside labels, so labels only need to be unique among siblings. Siblings that
+/// share a label are merged into a single node whose value is their sum. Nodes without a value get
+/// a value of zero, which draws them with the size of their children under the default
+/// `BranchValues::Remainder`.
+#[derive(Debug, Default)]
+pub struct Hierarchy {
+    pub(crate) ids: Vec<String>,
+    pub(crate) labels: Vec<String>,
+    pub(crate) parents: Vec<String>,
+    pub(crate) values: Vec<f64>,
+}
+
+impl Hierarchy {
+    pub fn from_tree(roots: Vec<Node>) -> Hierarchy {
+        let mut hierarchy = Hierarchy::default();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for root in roots.iter() {
+            hierarchy.push_node(&mut index, root, "");
+        }
+        hierarchy
+    }
+
+    /// Builds a hierarchy from `(path, value)` tuples, where `path` holds the labels from a root
+    /// down to a leaf. Intermediate nodes are created the first time they appear on a path.
+    pub fn from_paths<S: AsRef<str>>(paths: Vec<(Vec<S>, f64)>) -> Hierarchy {
+        let mut hierarchy = Hierarchy::default();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for (path, value) in paths.iter() {
+            let mut parent = String::new();
+            for (depth, label) in path.iter().enumerate() {
+                let i = hierarchy.node(&mut index, &parent, label.as_ref());
+                if depth == path.len() - 1 {
+                    hierarchy.values[i] += value;
+                }
+                parent = hierarchy.ids[i].clone();
+            }
+        }
+        hierarchy
+    }
+
+    fn push_node(&mut self, index: &mut HashMap<String, usize>, node: &Node, parent: &str) {
+        let i = self.node(index, parent, &node.label);
+        self.values[i] += node.value.unwrap_or(0.0);
+        let id = self.ids[i].clone();
+        for child in node.children.iter() {
+            self.push_node(index, child, &id);
+        }
+    }
+
+    /// Returns the position of the child of `parent` labelled `label`, appending it on first use.
+    fn node(&mut self, index: &mut HashMap<String, usize>, parent: &str, label: &str) -> usize {
+        let id = Hierarchy::child_id(parent, label);
+        if let Some(&i) = index.get(&id) {
+            return i;
+        }
+        self.ids.push(id.clone());
+        self.labels.push(label.to_owned());
+        self.parents.push(parent.to_owned());
+        self.values.push(0.0);
+        index.insert(id, self.ids.len() - 1);
+        self.ids.len() - 1
+    }
+
+    fn child_id(parent: &str, label: &str) -> String {
+        let label = label.replace('\\', "\\\\").replace('/', "\\/");
+        if parent.is_empty() {
+            label
+        } else {
+            format!("{}/{}", parent, label)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hierarchy_from_tree() {
+        let tree = Node::new("Eve").child(Node::new("Cain").value(14.0)).child(
+            Node::new("Seth")
+                .value(12.0)
+                .child(Node::new("Enos").value(10.0)),
+        );
+        let hierarchy = Hierarchy::from_tree(vec![tree]);
+        assert_eq!(
+            hierarchy.ids,
+            vec!["Eve", "Eve/Cain", "Eve/Seth", "Eve/Seth/Enos"]
+        );
+        assert_eq!(hierarchy.labels, vec!["Eve", "Cain", "Seth", "Enos"]);
+        assert_eq!(hierarchy.parents, vec!["", "Eve", "Eve", "Eve/Seth"]);
+        assert_eq!(hierarchy.values, vec![0.0, 14.0, 12.0, 10.0]);
+    }
+
+    #[test]
+    fn test_hierarchy_from_paths() {
+        let hierarchy = Hierarchy::from_paths(vec![
+            (vec!["Europe", "France"], 67.0),
+            (vec!["Europe", "Germany"], 83.0),
+            (vec!["Asia", "Japan"], 126.0),
+            (vec!["Europe", "France"], 1.0),
+        ]);
+        assert_eq!(
+            hierarchy.ids,
+            vec![
+                "Europe",
+                "Europe/France",
+                "Europe/Germany",
+                "Asia",
+                "Asia/Japan"
+            ]
+        );
+        assert_eq!(hierarchy.parents, vec!["", "Europe", "Europe", "", "Asia"]);
+        assert_eq!(hierarchy.values, vec![0.0, 68.0, 83.0, 0.0, 126.0]);
+    }
+
+    #[test]
+    fn test_hierarchy_ids_escape_separator() {
+        let hierarchy = Hierarchy::from_paths(vec![
+            (vec!["x", "a/b"], 1.0),
+            (vec!["x", "a", "b"], 2.0),
+            (vec!["x", "a\\", "b"], 3.0),
+        ]);
+        assert_eq!(
+            hierarchy.ids,
+            vec!["x", "x/a\\/b", "x/a", "x/a/b", "x/a\\\\", "x/a\\\\/b"]
+        );
+        assert_eq!(hierarchy.labels, vec!["x", "a/b", "a", "b", "a\\", "b"]);
+        assert_eq!(hierarchy.values, vec![0.0, 1.0, 0.0, 2.0, 0.0, 3.0]);
+    }
+
+    #[test]
+    fn test_hierarchy_from_tree_merges_duplicate_siblings() {
+        let tree = Node::new("Eve").child(Node::new("Cain").value(14.0)).child(
+            Node::new("Cain")
+                .value(1.0)
+                .child(Node::new("Enoch").value(1.0)),
+        );
+        let hierarchy = Hierarchy::from_tree(vec![tree]);
+        assert_eq!(hierarchy.ids, vec!["Eve", "Eve/Cain", "Eve/Cain/Enoch"]);
+        assert_eq!(hierarchy.parents, vec!["", "Eve", "Eve/Cain"]);
+        assert_eq!(hierarchy.values, vec![0.0, 15.0, 1.0]);
+    }
+}
@@ -1,18 +1,20 @@
-use serde::Serialize;
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 pub mod color;
+pub mod hierarchy;
 
 use crate::private;
 use color::Color;
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
 pub enum Direction {
     Increasing { line: Line },
     Decreasing { line: Line },
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum HoverInfo {
     #[serde(rename = "x")]
     X,
@@ -40,7 +42,7 @@ pub enum HoverInfo {
     Skip,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum TextPosition {
     #[serde(rename = "inside")]
     Inside,
@@ -52,7 +54,7 @@ pub enum TextPosition {
     None,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ConstrainText {
     #[serde(rename = "inside")]
     Inside,
@@ -64,7 +66,7 @@ pub enum ConstrainText {
     None,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum Orientation {
     #[serde(rename = "v")]
     Vertical,
@@ -72,7 +74,37 @@ pub enum Orientation {
     Horizontal,
 }
 
-#[derive(Serialize, Debug)]
+/// The information shown on hover over a `ScatterPolar` or `BarPolar` trace. Several flags are
+/// combined, e.g. `vec![PolarHoverInfo::R, PolarHoverInfo::Theta]`.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum PolarHoverInfo {
+    #[serde(rename = "r")]
+    R,
+    #[serde(rename = "theta")]
+    Theta,
+    #[serde(rename = "text")]
+    Text,
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "skip")]
+    Skip,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ThetaUnit {
+    #[serde(rename = "radians")]
+    Radians,
+    #[serde(rename = "degrees")]
+    Degrees,
+    #[serde(rename = "gradians")]
+    Gradians,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
 pub enum GroupNorm {
     #[serde(rename = "")]
     Default,
@@ -82,7 +114,7 @@ pub enum GroupNorm {
     Percent,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum Fill {
     #[serde(rename = "tozeroy")]
     ToZeroY,
@@ -100,7 +132,7 @@ pub enum Fill {
     None,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum Calendar {
     #[serde(rename = "gregorian")]
     Gregorian,
@@ -136,7 +168,7 @@ pub enum Calendar {
     Ummalqura,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
 pub enum Dim<T>
 where
@@ -144,16 +176,43 @@ where
 {
     Scalar(T),
     Vector(Vec<T>),
+    /// An array of numbers where `T` is not numeric, e.g. marker colors mapped to a colorscale.
+    /// Missing values are represented by `None`.
+    NumericVector(Vec<Option<f64>>),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Visible {
+    #[serde(rename = "true")]
+    True,
+    #[serde(rename = "false")]
+    False,
+    #[serde(rename = "legendonly")]
+    LegendOnly,
+}
+
+impl From<bool> for Visible {
+    fn from(visible: bool) -> Self {
+        if visible {
+            Visible::True
+        } else {
+            Visible::False
+        }
+    }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum PlotType {
     #[serde(rename = "scatter")]
     Scatter,
+    #[serde(rename = "scatterpolar")]
+    ScatterPolar,
     #[serde(rename = "scatter3d")]
     Scatter3D,
     #[serde(rename = "bar")]
     Bar,
+    #[serde(rename = "barpolar")]
+    BarPolar,
     #[serde(rename = "box")]
     Box,
     #[serde(rename = "candlestick")]
@@ -164,15 +223,29 @@ pub enum PlotType {
     HeatMap,
     #[serde(rename = "histogram")]
     Histogram,
+    #[serde(rename = "histogram2d")]
+    Histogram2d,
     #[serde(rename = "histogram2dcontour")]
     Histogram2dContour,
     #[serde(rename = "ohlc")]
     Ohlc,
+    #[serde(rename = "pie")]
+    Pie,
+    #[serde(rename = "sankey")]
+    Sankey,
+    #[serde(rename = "sunburst")]
+    Sunburst,
     #[serde(rename = "surface")]
     Surface,
+    #[serde(rename = "treemap")]
+    Treemap,
+    #[serde(rename = "violin")]
+    Violin,
+    #[serde(rename = "waterfall")]
+    Waterfall,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum Mode {
     #[serde(rename = "lines")]
     Lines,
@@ -192,7 +265,7 @@ pub enum Mode {
     None,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum Position {
     #[serde(rename = "top left")]
     TopLeft,
@@ -214,7 +287,7 @@ pub enum Position {
     BottomRight,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum MarkerSymbol {
     #[serde(rename = "circle")]
     Circle,
@@ -502,7 +575,7 @@ pub enum MarkerSymbol {
     LineNWOpen,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum TickMode {
     #[serde(rename = "auto")]
     Auto,
@@ -512,7 +585,7 @@ pub enum TickMode {
     Array,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum DashType {
     #[serde(rename = "solid")]
     Solid,
@@ -520,7 +593,7 @@ pub enum DashType {
     Dot,
     #[serde(rename = "dash")]
     Dash,
-    #[serde(rename = "longdashdot")]
+    #[serde(rename = "longdash")]
     LongDash,
     #[serde(rename = "dashdot")]
     DashDot,
@@ -528,10 +601,10 @@ pub enum DashType {
     LongDashDot,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub struct ColorScaleElement(f64, String);
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ColorScalePalette {
     Greys,
     YlGnBu,
@@ -553,14 +626,14 @@ pub enum ColorScalePalette {
     Cividis,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
 pub enum ColorScale {
     Palette(ColorScalePalette),
     Vector(Vec<ColorScaleElement>),
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum LineShape {
     #[serde(rename = "linear")]
     Linear,
@@ -576,7 +649,8 @@ pub enum LineShape {
     Vhv,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Line {
     #[serde(skip_serializing_if = "Option::is_none")]
     width: Option<f64>,
@@ -707,7 +781,7 @@ impl Line {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum GradientType {
     #[serde(rename = "radial")]
     Radial,
@@ -719,7 +793,7 @@ pub enum GradientType {
     None,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum SizeMode {
     #[serde(rename = "diameter")]
     Diameter,
@@ -727,7 +801,7 @@ pub enum SizeMode {
     Area,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ThicknessMode {
     #[serde(rename = "fraction")]
     Fraction,
@@ -735,7 +809,7 @@ pub enum ThicknessMode {
     Pixels,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum Anchor {
     #[serde(rename = "auto")]
     Auto,
@@ -753,7 +827,7 @@ pub enum Anchor {
     Bottom,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum TextAnchor {
     #[serde(rename = "start")]
     Start,
@@ -763,7 +837,7 @@ pub enum TextAnchor {
     End,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ExponentFormat {
     #[serde(rename = "none")]
     None,
@@ -779,7 +853,8 @@ pub enum ExponentFormat {
     B,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Gradient {
     r#type: GradientType,
     color: Dim<String>,
@@ -790,6 +865,7 @@ impl Gradient {
         let color = match color {
             Dim::Scalar(c) => Dim::Scalar(c.to_color_string()),
             Dim::Vector(c) => Dim::Vector(private::to_color_array(c)),
+            Dim::NumericVector(c) => Dim::NumericVector(c),
         };
         Gradient {
             r#type: gradient_type,
@@ -798,7 +874,8 @@ impl Gradient {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct TickFormatStops {
     enabled: bool,
     #[serde(skip_serializing_if = "Option::is_none", rename = "dtickrange")]
@@ -848,7 +925,14 @@ impl TickFormatStops {
     }
 }
 
-#[derive(Serialize, Debug)]
+impl Default for TickFormatStops {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct ColorBar {
     #[serde(skip_serializing_if = "Option::is_none", rename = "thicknessmode")]
     thickness_mode: Option<ThicknessMode>,
@@ -1158,7 +1242,14 @@ impl ColorBar {
     }
 }
 
-#[derive(Serialize, Debug)]
+impl Default for ColorBar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Marker {
     #[serde(skip_serializing_if = "Option::is_none")]
     symbol: Option<MarkerSymbol>,
@@ -1172,7 +1263,7 @@ pub struct Marker {
     size_ref: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "sizemin")]
     size_min: Option<usize>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "sizemin")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sizemode")]
     size_mode: Option<SizeMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
     line: Option<Line>,
@@ -1340,7 +1431,8 @@ impl Marker {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Font {
     #[serde(skip_serializing_if = "Option::is_none")]
     family: Option<String>,
@@ -1375,7 +1467,7 @@ impl Font {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum Side {
     #[serde(rename = "right")]
     Right,
@@ -1389,7 +1481,7 @@ pub enum Side {
     TopLeft,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum Reference {
     #[serde(rename = "container")]
     Container,
@@ -1397,7 +1489,111 @@ pub enum Reference {
     Paper,
 }
 
-#[derive(Serialize, Debug)]
+/// The coordinates in which the position of an `Annotation` or `LayoutImage` is given: the plotting
+/// area, where 0 and 1 are its edges, or the data of the axis with the given id.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AxisReference {
+    Paper,
+    /// Pixels relative to the annotated point. Only valid for `Annotation::ax_ref` and
+    /// `Annotation::ay_ref`.
+    Pixel,
+    /// An axis id such as `"x"` or `"y2"`.
+    Axis(String),
+}
+
+impl AxisReference {
+    /// The axis with the given id, e.g. `AxisReference::axis("x2")`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id` is not an x or y axis id.
+    pub fn axis(id: &str) -> AxisReference {
+        if !private::is_indexed_name(id, "x") && !private::is_indexed_name(id, "y") {
+            panic!("`{}` is not an axis id such as \"x\" or \"y2\"", id);
+        }
+        AxisReference::Axis(id.to_owned())
+    }
+}
+
+impl Serialize for AxisReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AxisReference::Paper => serializer.serialize_str("paper"),
+            AxisReference::Pixel => serializer.serialize_str("pixel"),
+            AxisReference::Axis(id) => serializer.serialize_str(id),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AxisReference {
+    fn deserialize<D>(deserializer: D) -> Result<AxisReference, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let reference = String::deserialize(deserializer)?;
+        match reference.as_str() {
+            "paper" => Ok(AxisReference::Paper),
+            "pixel" => Ok(AxisReference::Pixel),
+            id if private::is_indexed_name(id, "x") || private::is_indexed_name(id, "y") => {
+                Ok(AxisReference::Axis(reference))
+            }
+            _ => Err(de::Error::custom(format!(
+                "unknown axis reference `{}`",
+                reference
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Domain {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    row: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    column: Option<usize>,
+}
+
+impl Domain {
+    pub fn new() -> Domain {
+        Domain {
+            x: None,
+            y: None,
+            row: None,
+            column: None,
+        }
+    }
+
+    pub fn x(mut self, x: Vec<f64>) -> Domain {
+        self.x = Some(x);
+        self
+    }
+
+    pub fn y(mut self, y: Vec<f64>) -> Domain {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn row(mut self, row: usize) -> Domain {
+        self.row = Some(row);
+        self
+    }
+
+    pub fn column(mut self, column: usize) -> Domain {
+        self.column = Some(column);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Pad {
     t: usize,
     b: usize,
@@ -1410,7 +1606,9 @@ impl Pad {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+#[serde(remote = "Self")]
 pub struct Title {
     text: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1495,7 +1693,30 @@ impl Title {
     }
 }
 
-#[derive(Serialize, Debug)]
+impl Serialize for Title {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Title::serialize(self, serializer)
+    }
+}
+
+// Plotly.js also accepts a title given as a plain string, which Python Plotly exports as is.
+impl<'de> Deserialize<'de> for Title {
+    fn deserialize<D>(deserializer: D) -> Result<Title, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::String(text) => Ok(Title::new(&text)),
+            title => Title::deserialize(title).map_err(de::Error::custom),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Label {
     #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
     background_color: Option<String>,
@@ -1551,7 +1772,7 @@ impl Label {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ErrorType {
     #[serde(rename = "percent")]
     Percent,
@@ -1563,7 +1784,8 @@ pub enum ErrorType {
     Data,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct ErrorData {
     r#type: ErrorType,
     #[serde(skip_serializing_if = "Option::is_none")]
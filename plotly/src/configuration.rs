@@ -0,0 +1,410 @@
+//! Plotly.js configuration options
+
+use crate::private;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ImageButtonFormats {
+    #[serde(rename = "png")]
+    Png,
+    #[serde(rename = "svg")]
+    Svg,
+    #[serde(rename = "jpeg")]
+    Jpeg,
+    #[serde(rename = "webp")]
+    Webp,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ToImageButtonOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<ImageButtonFormats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filename: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scale: Option<f64>,
+}
+
+impl ToImageButtonOptions {
+    pub fn new() -> ToImageButtonOptions {
+        ToImageButtonOptions {
+            format: None,
+            filename: None,
+            height: None,
+            width: None,
+            scale: None,
+        }
+    }
+
+    pub fn format(mut self, format: ImageButtonFormats) -> ToImageButtonOptions {
+        self.format = Some(format);
+        self
+    }
+
+    pub fn filename(mut self, filename: &str) -> ToImageButtonOptions {
+        self.filename = Some(filename.to_owned());
+        self
+    }
+
+    pub fn height(mut self, height: usize) -> ToImageButtonOptions {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn width(mut self, width: usize) -> ToImageButtonOptions {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn scale(mut self, scale: f64) -> ToImageButtonOptions {
+        self.scale = Some(scale);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum DisplayModeBar {
+    #[serde(rename = "hover")]
+    Hover,
+    #[serde(rename = "true")]
+    True,
+    #[serde(rename = "false")]
+    False,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum DoubleClick {
+    #[serde(rename = "false")]
+    False,
+    #[serde(rename = "reset")]
+    Reset,
+    #[serde(rename = "autosize")]
+    AutoSize,
+    #[serde(rename = "reset+autosize")]
+    ResetAutoSize,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ModeBarButtonName {
+    #[serde(rename = "zoom2d")]
+    Zoom2d,
+    #[serde(rename = "pan2d")]
+    Pan2d,
+    #[serde(rename = "select2d")]
+    Select2d,
+    #[serde(rename = "lasso2d")]
+    Lasso2d,
+    #[serde(rename = "zoomIn2d")]
+    ZoomIn2d,
+    #[serde(rename = "zoomOut2d")]
+    ZoomOut2d,
+    #[serde(rename = "autoScale2d")]
+    AutoScale2d,
+    #[serde(rename = "resetScale2d")]
+    ResetScale2d,
+    #[serde(rename = "zoom3d")]
+    Zoom3d,
+    #[serde(rename = "pan3d")]
+    Pan3d,
+    #[serde(rename = "orbitRotation")]
+    OrbitRotation,
+    #[serde(rename = "tableRotation")]
+    TableRotation,
+    #[serde(rename = "resetCameraDefault3d")]
+    ResetCameraDefault3d,
+    #[serde(rename = "resetCameraLastSave3d")]
+    ResetCameraLastSave3d,
+    #[serde(rename = "hoverClosest3d")]
+    HoverClosest3d,
+    #[serde(rename = "hoverClosestCartesian")]
+    HoverClosestCartesian,
+    #[serde(rename = "hoverCompareCartesian")]
+    HoverCompareCartesian,
+    #[serde(rename = "zoomInGeo")]
+    ZoomInGeo,
+    #[serde(rename = "zoomOutGeo")]
+    ZoomOutGeo,
+    #[serde(rename = "resetGeo")]
+    ResetGeo,
+    #[serde(rename = "hoverClosestGeo")]
+    HoverClosestGeo,
+    #[serde(rename = "hoverClosestGl2d")]
+    HoverClosestGl2d,
+    #[serde(rename = "hoverClosestPie")]
+    HoverClosestPie,
+    #[serde(rename = "toggleHover")]
+    ToggleHover,
+    #[serde(rename = "resetViews")]
+    ResetViews,
+    #[serde(rename = "toImage")]
+    ToImage,
+    #[serde(rename = "sendDataToCloud")]
+    SendDataToCloud,
+    #[serde(rename = "toggleSpikelines")]
+    ToggleSpikelines,
+    #[serde(rename = "resetViewMapbox")]
+    ResetViewMapbox,
+    #[serde(rename = "zoomInMapbox")]
+    ZoomInMapbox,
+    #[serde(rename = "zoomOutMapbox")]
+    ZoomOutMapbox,
+}
+
+/// The plotly.js config object, which controls the interactive behaviour of a plot; in contrast
+/// to the `Layout`, which controls its appearance.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Configuration {
+    #[serde(skip_serializing_if = "Option::is_none", rename = "staticPlot")]
+    static_plot: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    editable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    autosizable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    responsive: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fillFrame")]
+    fill_frame: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "frameMargins")]
+    frame_margins: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "scrollZoom")]
+    scroll_zoom: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "doubleClick")]
+    double_click: Option<private::TruthyEnum<DoubleClick>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "doubleClickDelay")]
+    double_click_delay: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showAxisDragHandles")]
+    show_axis_drag_handles: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showAxisRangeEntryBoxes")]
+    show_axis_range_entry_boxes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showTips")]
+    show_tips: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showLink")]
+    show_link: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linkText")]
+    link_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sendData")]
+    send_data: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "displayModeBar")]
+    display_mode_bar: Option<private::TruthyEnum<DisplayModeBar>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showSendToCloud")]
+    show_send_to_cloud: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showEditInChartStudio")]
+    show_edit_in_chart_studio: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "modeBarButtonsToRemove")]
+    mode_bar_buttons_to_remove: Option<Vec<ModeBarButtonName>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "toImageButtonOptions")]
+    to_image_button_options: Option<ToImageButtonOptions>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "displaylogo")]
+    display_logo: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    watermark: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "plotGlPixelRatio")]
+    plot_gl_pixel_ratio: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "mapboxAccessToken")]
+    mapbox_access_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "queueLength")]
+    queue_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locale: Option<String>,
+}
+
+impl Configuration {
+    pub fn new() -> Configuration {
+        Configuration {
+            static_plot: None,
+            editable: None,
+            autosizable: None,
+            responsive: None,
+            fill_frame: None,
+            frame_margins: None,
+            scroll_zoom: None,
+            double_click: None,
+            double_click_delay: None,
+            show_axis_drag_handles: None,
+            show_axis_range_entry_boxes: None,
+            show_tips: None,
+            show_link: None,
+            link_text: None,
+            send_data: None,
+            display_mode_bar: None,
+            show_send_to_cloud: None,
+            show_edit_in_chart_studio: None,
+            mode_bar_buttons_to_remove: None,
+            to_image_button_options: None,
+            display_logo: None,
+            watermark: None,
+            plot_gl_pixel_ratio: None,
+            mapbox_access_token: None,
+            queue_length: None,
+            locale: None,
+        }
+    }
+
+    pub fn static_plot(mut self, static_plot: bool) -> Configuration {
+        self.static_plot = Some(static_plot);
+        self
+    }
+
+    pub fn editable(mut self, editable: bool) -> Configuration {
+        self.editable = Some(editable);
+        self
+    }
+
+    pub fn autosizable(mut self, autosizable: bool) -> Configuration {
+        self.autosizable = Some(autosizable);
+        self
+    }
+
+    pub fn responsive(mut self, responsive: bool) -> Configuration {
+        self.responsive = Some(responsive);
+        self
+    }
+
+    pub fn fill_frame(mut self, fill_frame: bool) -> Configuration {
+        self.fill_frame = Some(fill_frame);
+        self
+    }
+
+    pub fn frame_margins(mut self, frame_margins: f64) -> Configuration {
+        self.frame_margins = Some(frame_margins);
+        self
+    }
+
+    pub fn scroll_zoom(mut self, scroll_zoom: bool) -> Configuration {
+        self.scroll_zoom = Some(scroll_zoom);
+        self
+    }
+
+    pub fn double_click(mut self, double_click: DoubleClick) -> Configuration {
+        self.double_click = Some(private::TruthyEnum { e: double_click });
+        self
+    }
+
+    pub fn double_click_delay(mut self, double_click_delay: usize) -> Configuration {
+        self.double_click_delay = Some(double_click_delay);
+        self
+    }
+
+    pub fn show_axis_drag_handles(mut self, show_axis_drag_handles: bool) -> Configuration {
+        self.show_axis_drag_handles = Some(show_axis_drag_handles);
+        self
+    }
+
+    pub fn show_axis_range_entry_boxes(
+        mut self,
+        show_axis_range_entry_boxes: bool,
+    ) -> Configuration {
+        self.show_axis_range_entry_boxes = Some(show_axis_range_entry_boxes);
+        self
+    }
+
+    pub fn show_tips(mut self, show_tips: bool) -> Configuration {
+        self.show_tips = Some(show_tips);
+        self
+    }
+
+    pub fn show_link(mut self, show_link: bool) -> Configuration {
+        self.show_link = Some(show_link);
+        self
+    }
+
+    pub fn link_text(mut self, link_text: &str) -> Configuration {
+        self.link_text = Some(link_text.to_owned());
+        self
+    }
+
+    pub fn send_data(mut self, send_data: bool) -> Configuration {
+        self.send_data = Some(send_data);
+        self
+    }
+
+    pub fn display_mode_bar(mut self, display_mode_bar: DisplayModeBar) -> Configuration {
+        self.display_mode_bar = Some(private::TruthyEnum { e: display_mode_bar });
+        self
+    }
+
+    pub fn show_send_to_cloud(mut self, show_send_to_cloud: bool) -> Configuration {
+        self.show_send_to_cloud = Some(show_send_to_cloud);
+        self
+    }
+
+    pub fn show_edit_in_chart_studio(mut self, show_edit_in_chart_studio: bool) -> Configuration {
+        self.show_edit_in_chart_studio = Some(show_edit_in_chart_studio);
+        self
+    }
+
+    pub fn mode_bar_buttons_to_remove(
+        mut self,
+        mode_bar_buttons_to_remove: Vec<ModeBarButtonName>,
+    ) -> Configuration {
+        self.mode_bar_buttons_to_remove = Some(mode_bar_buttons_to_remove);
+        self
+    }
+
+    pub fn to_image_button_options(
+        mut self,
+        to_image_button_options: ToImageButtonOptions,
+    ) -> Configuration {
+        self.to_image_button_options = Some(to_image_button_options);
+        self
+    }
+
+    pub fn display_logo(mut self, display_logo: bool) -> Configuration {
+        self.display_logo = Some(display_logo);
+        self
+    }
+
+    pub fn watermark(mut self, watermark: bool) -> Configuration {
+        self.watermark = Some(watermark);
+        self
+    }
+
+    pub fn plot_gl_pixel_ratio(mut self, plot_gl_pixel_ratio: f64) -> Configuration {
+        self.plot_gl_pixel_ratio = Some(plot_gl_pixel_ratio);
+        self
+    }
+
+    pub fn mapbox_access_token(mut self, mapbox_access_token: &str) -> Configuration {
+        self.mapbox_access_token = Some(mapbox_access_token.to_owned());
+        self
+    }
+
+    pub fn queue_length(mut self, queue_length: usize) -> Configuration {
+        self.queue_length = Some(queue_length);
+        self
+    }
+
+    pub fn locale(mut self, locale: &str) -> Configuration {
+        self.locale = Some(locale.to_owned());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_configuration() {
+        let config = Configuration::new()
+            .static_plot(true)
+            .display_mode_bar(DisplayModeBar::Hover)
+            .double_click(DoubleClick::False)
+            .mode_bar_buttons_to_remove(vec![ModeBarButtonName::Lasso2d])
+            .to_image_button_options(
+                ToImageButtonOptions::new()
+                    .format(ImageButtonFormats::Svg)
+                    .scale(1.5),
+            )
+            .display_logo(false)
+            .locale("en-GB");
+        let expected = r#"{"staticPlot":true,"doubleClick":false,"displayModeBar":"hover","modeBarButtonsToRemove":["lasso2d"],"toImageButtonOptions":{"format":"svg","scale":1.5},"displaylogo":false,"locale":"en-GB"}"#;
+        assert_eq!(serde_json::to_string(&config).unwrap(), expected);
+    }
+}
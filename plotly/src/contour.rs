@@ -1,12 +1,14 @@
 //! Contour plot
 
 use crate::common::color::Color;
-use crate::common::{Calendar, ColorBar, ColorScale, Dim, Font, HoverInfo, Label, Line, PlotType};
+use crate::common::{
+    Calendar, ColorBar, ColorScale, Dim, Font, HoverInfo, Label, Line, PlotType, Visible,
+};
 use crate::private;
 use crate::Trace;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ContoursType {
     #[serde(rename = "levels")]
     Levels,
@@ -14,7 +16,7 @@ pub enum ContoursType {
     Constraint,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ContoursColoring {
     #[serde(rename = "fill")]
     Fill,
@@ -26,7 +28,8 @@ pub enum ContoursColoring {
     None,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Contours {
     #[serde(skip_serializing_if = "Option::is_none")]
     r#type: Option<ContoursType>,
@@ -38,7 +41,7 @@ pub struct Contours {
     size: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     coloring: Option<ContoursColoring>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlines")]
     show_lines: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showlabels")]
     show_labels: Option<bool>,
@@ -125,7 +128,14 @@ impl Contours {
     }
 }
 
-#[derive(Serialize, Debug)]
+impl Default for Contours {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Contour<Z, X = f64, Y = f64>
 where
     X: Serialize,
@@ -136,7 +146,11 @@ where
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    visible: Option<bool>,
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
     show_legend: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
@@ -210,6 +224,10 @@ where
     x_calendar: Option<Calendar>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<String>,
 }
 
 impl<Z> Contour<Z, f64, f64>
@@ -225,6 +243,8 @@ where Z: Serialize {
             dy: None,
             z,
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -254,6 +274,8 @@ where Z: Serialize {
             transpose: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 }
@@ -276,6 +298,8 @@ where
             dy: None,
             z,
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -305,6 +329,8 @@ where
             transpose: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 
@@ -343,8 +369,18 @@ where
         Box::new(self)
     }
 
-    pub fn visible(mut self, visible: bool) -> Box<Contour<Z, X, Y>> {
-        self.visible = Some(visible);
+    pub fn uid(mut self, uid: &str) -> Box<Contour<Z, X, Y>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Contour<Z, X, Y>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Contour<Z, X, Y>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
         Box::new(self)
     }
 
@@ -498,6 +534,16 @@ where
         self.y_calendar = Some(y_calendar);
         Box::new(self)
     }
+
+    pub fn x_axis(mut self, x_axis: &str) -> Box<Contour<Z, X, Y>> {
+        self.x_axis = Some(x_axis.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, y_axis: &str) -> Box<Contour<Z, X, Y>> {
+        self.y_axis = Some(y_axis.to_owned());
+        Box::new(self)
+    }
 }
 
 impl<X, Y, Z> Trace for Contour<X, Y, Z>
@@ -0,0 +1,115 @@
+//! Errors returned by the fallible `Plot` output methods, when placing traces in subplots and when
+//! loading plots and images.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::process::ExitStatus;
+
+/// The error type returned by the `try_*` methods of `Plot`, by `Plot::from_json` and by
+/// `LayoutImage::source_file`.
+#[derive(Debug)]
+pub enum Error {
+    /// Creating or writing the output file failed.
+    Io(std::io::Error),
+    /// Rendering the html template failed.
+    Render(askama::Error),
+    /// The id given for the `<div>` of an inline plot is not a valid html id.
+    InvalidDivId(String),
+    /// Serializing or deserializing the plot as JSON failed.
+    Json(serde_json::Error),
+    /// The system default application for html files could not be launched.
+    DefaultAppNotFound(std::io::Error),
+    /// The system default application for html files exited with a non-zero status.
+    DefaultAppFailed { status: ExitStatus, stderr: String },
+    /// Reading an image file to embed in the plot failed.
+    ImageFile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// The image file to embed in the plot has an extension that is not a known image format.
+    UnsupportedImageFormat(PathBuf),
+    /// A trace was added to a subplot that the plot does not have, e.g. a cell outside its
+    /// `Subplots` grid.
+    InvalidSubplot(String),
+    /// Converting the plot with Orca failed.
+    #[cfg(feature = "orca")]
+    Orca(plotly_orca::Error),
+}
+
+const DEFAULT_HTML_APP_NOT_FOUND: &str = r#"Could not find default application for HTML files.
+Consider using the `to_html` method to save the plot instead. If using the `orca` feature the following
+additional formats are available accessed by following methods:
+- to_png
+- to_jpeg
+- to_webp
+- to_svg
+- to_pdf
+- to_eps
+"#;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "failed to write plot output: {}", e),
+            Error::Render(e) => write!(f, "failed to render plot template: {}", e),
+            Error::InvalidDivId(id) => write!(f, "invalid plot div id: {:?}", id),
+            Error::Json(e) => write!(f, "invalid plot json: {}", e),
+            Error::DefaultAppNotFound(e) => write!(f, "{}({})", DEFAULT_HTML_APP_NOT_FOUND, e),
+            Error::DefaultAppFailed { status, stderr } => {
+                write!(f, "default html application exited with {}: {}", status, stderr.trim())
+            }
+            Error::ImageFile { path, source } => {
+                write!(f, "failed to read image file {}: {}", path.display(), source)
+            }
+            Error::UnsupportedImageFormat(path) => {
+                write!(f, "unsupported image format: {}", path.display())
+            }
+            Error::InvalidSubplot(msg) => write!(f, "invalid subplot: {}", msg),
+            #[cfg(feature = "orca")]
+            Error::Orca(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Render(e) => Some(e),
+            Error::InvalidDivId(_) => None,
+            Error::Json(e) => Some(e),
+            Error::DefaultAppNotFound(e) => Some(e),
+            Error::DefaultAppFailed { .. } => None,
+            Error::ImageFile { source, .. } => Some(source),
+            Error::UnsupportedImageFormat(_) => None,
+            Error::InvalidSubplot(_) => None,
+            #[cfg(feature = "orca")]
+            Error::Orca(e) => Some(e),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<askama::Error> for Error {
+    fn from(e: askama::Error) -> Self {
+        Error::Render(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+#[cfg(feature = "orca")]
+impl From<plotly_orca::Error> for Error {
+    fn from(e: plotly_orca::Error) -> Self {
+        Error::Orca(e)
+    }
+}
@@ -1,11 +1,14 @@
 //! Heat-map plot
 
-use crate::common::{Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType};
+use crate::common::{
+    Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType, Visible,
+};
 use crate::private;
 use crate::Trace;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct HeatMap<Z, X, Y>
 where
     X: Serialize,
@@ -16,14 +19,20 @@ where
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    visible: Option<bool>,
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
     show_legend: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
     legend_group: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     x: Option<Vec<X>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     y: Option<Vec<Y>>,
     z: Vec<Z>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -68,6 +77,10 @@ where
     x_calendar: Option<Calendar>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<String>,
 }
 
 impl<Z> HeatMap<Z, f64, f64>
@@ -79,6 +92,8 @@ where Z: Serialize {
             z,
             r#type: PlotType::HeatMap,
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -104,6 +119,8 @@ where Z: Serialize {
             transpose: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 }
@@ -121,6 +138,8 @@ where
             z,
             r#type: PlotType::HeatMap,
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -146,6 +165,8 @@ where
             transpose: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 
@@ -154,8 +175,18 @@ where
         Box::new(self)
     }
 
-    pub fn visible(mut self, visible: bool) -> Box<HeatMap<Z, X, Y>> {
-        self.visible = Some(visible);
+    pub fn uid(mut self, uid: &str) -> Box<HeatMap<Z, X, Y>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<HeatMap<Z, X, Y>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<HeatMap<Z, X, Y>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
         Box::new(self)
     }
 
@@ -289,6 +320,16 @@ where
         self.y_calendar = Some(calendar);
         Box::new(self)
     }
+
+    pub fn x_axis(mut self, x_axis: &str) -> Box<HeatMap<Z, X, Y>> {
+        self.x_axis = Some(x_axis.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, y_axis: &str) -> Box<HeatMap<Z, X, Y>> {
+        self.y_axis = Some(y_axis.to_owned());
+        Box::new(self)
+    }
 }
 
 impl<X, Y, Z> Trace for HeatMap<Z, X, Y>
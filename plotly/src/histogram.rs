@@ -1,12 +1,15 @@
 //! Histogram plot
 
-use crate::common::{Calendar, Dim, ErrorData, HoverInfo, Label, Marker, Orientation, PlotType};
+use crate::common::{
+    Calendar, Dim, ErrorData, HoverInfo, Label, Marker, Orientation, PlotType, Visible,
+};
 use crate::Trace;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::private;
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Bins {
     start: f64,
     end: f64,
@@ -19,7 +22,7 @@ impl Bins {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum HistFunc {
     #[serde(rename = "count")]
     Count,
@@ -33,7 +36,7 @@ pub enum HistFunc {
     Maximum,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum HistNorm {
     #[serde(rename = "")]
     Default,
@@ -47,7 +50,7 @@ pub enum HistNorm {
     ProbabilityDensity,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum HistDirection {
     #[serde(rename = "increasing")]
     Increasing,
@@ -55,7 +58,7 @@ pub enum HistDirection {
     Decreasing,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum CurrentBin {
     #[serde(rename = "include")]
     Include,
@@ -65,7 +68,8 @@ pub enum CurrentBin {
     Half,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Cumulative {
     #[serde(skip_serializing_if = "Option::is_none")]
     enabled: Option<bool>,
@@ -100,7 +104,8 @@ impl Cumulative {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Histogram<H>
 where
     H: Serialize,
@@ -109,7 +114,11 @@ where
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    visible: Option<bool>,
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
     show_legend: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
@@ -128,6 +137,7 @@ where
     hover_info: Option<HoverInfo>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
     hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     orientation: Option<Orientation>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "histfunc")]
     hist_func: Option<HistFunc>,
@@ -165,6 +175,10 @@ where
     x_calendar: Option<Calendar>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
     y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<String>,
 }
 
 impl<H> Histogram<H>
@@ -177,6 +191,8 @@ where
             x: Some(x),
             y: None,
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -204,6 +220,8 @@ where
             hover_label: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 
@@ -213,6 +231,8 @@ where
             x: Some(x),
             y: Some(y),
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -240,6 +260,8 @@ where
             hover_label: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 
@@ -249,6 +271,8 @@ where
             x: None,
             y: Some(y),
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -276,6 +300,8 @@ where
             hover_label: None,
             x_calendar: None,
             y_calendar: None,
+            x_axis: None,
+            y_axis: None,
         })
     }
 
@@ -284,8 +310,18 @@ where
         Box::new(self)
     }
 
-    pub fn visible(mut self, visible: bool) -> Box<Histogram<H>> {
-        self.visible = Some(visible);
+    pub fn uid(mut self, uid: &str) -> Box<Histogram<H>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Histogram<H>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Histogram<H>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
         Box::new(self)
     }
 
@@ -439,6 +475,16 @@ where
         self.y_calendar = Some(y_calendar);
         Box::new(self)
     }
+
+    pub fn x_axis(mut self, x_axis: &str) -> Box<Histogram<H>> {
+        self.x_axis = Some(x_axis.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, y_axis: &str) -> Box<Histogram<H>> {
+        self.y_axis = Some(y_axis.to_owned());
+        Box::new(self)
+    }
 }
 
 impl<H> Trace for Histogram<H>
@@ -0,0 +1,782 @@
+//! Two-dimensional histogram and histogram contour plots
+
+use crate::common::{
+    Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, Line, Marker, PlotType, Visible,
+};
+use crate::contour::Contours;
+use crate::histogram::{Bins, HistFunc, HistNorm};
+use crate::private;
+use crate::Trace;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Histogram2d<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    r#type: PlotType,
+    x: Vec<X>,
+    y: Vec<Y>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    z: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "histfunc")]
+    hist_func: Option<HistFunc>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "histnorm")]
+    hist_norm: Option<HistNorm>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nbinsx")]
+    n_bins_x: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nbinsy")]
+    n_bins_y: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autobinx")]
+    auto_bin_x: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autobiny")]
+    auto_bin_y: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bingroup")]
+    bin_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xbingroup")]
+    x_bin_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ybingroup")]
+    y_bin_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xbins")]
+    x_bins: Option<Bins>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ybins")]
+    y_bins: Option<Bins>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xgap")]
+    x_gap: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ygap")]
+    y_gap: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zsmooth: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zhoverformat")]
+    zhover_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<String>,
+}
+
+impl<X, Y> Histogram2d<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Box<Histogram2d<X, Y>> {
+        Box::new(Histogram2d {
+            r#type: PlotType::Histogram2d,
+            x,
+            y,
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            z: None,
+            hover_info: None,
+            hover_template: None,
+            hist_func: None,
+            hist_norm: None,
+            n_bins_x: None,
+            n_bins_y: None,
+            auto_bin_x: None,
+            auto_bin_y: None,
+            bin_group: None,
+            x_bin_group: None,
+            y_bin_group: None,
+            x_bins: None,
+            y_bins: None,
+            marker: None,
+            x_gap: None,
+            y_gap: None,
+            zsmooth: None,
+            color_bar: None,
+            auto_color_scale: None,
+            color_scale: None,
+            show_scale: None,
+            reverse_scale: None,
+            zauto: None,
+            zhover_format: None,
+            zmax: None,
+            zmid: None,
+            zmin: None,
+            hover_label: None,
+            x_calendar: None,
+            y_calendar: None,
+            x_axis: None,
+            y_axis: None,
+        })
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Histogram2d<X, Y>> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<Histogram2d<X, Y>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Histogram2d<X, Y>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Histogram2d<X, Y>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Histogram2d<X, Y>> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Histogram2d<X, Y>> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Histogram2d<X, Y>> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn z(mut self, z: Vec<f64>) -> Box<Histogram2d<X, Y>> {
+        self.z = Some(z);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Histogram2d<X, Y>> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Histogram2d<X, Y>> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(
+        mut self,
+        hover_template: Vec<S>,
+    ) -> Box<Histogram2d<X, Y>> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hist_func(mut self, hist_func: HistFunc) -> Box<Histogram2d<X, Y>> {
+        self.hist_func = Some(hist_func);
+        Box::new(self)
+    }
+
+    pub fn hist_norm(mut self, hist_norm: HistNorm) -> Box<Histogram2d<X, Y>> {
+        self.hist_norm = Some(hist_norm);
+        Box::new(self)
+    }
+
+    pub fn n_bins_x(mut self, n_bins_x: usize) -> Box<Histogram2d<X, Y>> {
+        self.n_bins_x = Some(n_bins_x);
+        Box::new(self)
+    }
+
+    pub fn n_bins_y(mut self, n_bins_y: usize) -> Box<Histogram2d<X, Y>> {
+        self.n_bins_y = Some(n_bins_y);
+        Box::new(self)
+    }
+
+    pub fn auto_bin_x(mut self, auto_bin_x: bool) -> Box<Histogram2d<X, Y>> {
+        self.auto_bin_x = Some(auto_bin_x);
+        Box::new(self)
+    }
+
+    pub fn auto_bin_y(mut self, auto_bin_y: bool) -> Box<Histogram2d<X, Y>> {
+        self.auto_bin_y = Some(auto_bin_y);
+        Box::new(self)
+    }
+
+    pub fn bin_group(mut self, bin_group: &str) -> Box<Histogram2d<X, Y>> {
+        self.bin_group = Some(bin_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn x_bin_group(mut self, x_bin_group: &str) -> Box<Histogram2d<X, Y>> {
+        self.x_bin_group = Some(x_bin_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_bin_group(mut self, y_bin_group: &str) -> Box<Histogram2d<X, Y>> {
+        self.y_bin_group = Some(y_bin_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn x_bins(mut self, x_bins: Bins) -> Box<Histogram2d<X, Y>> {
+        self.x_bins = Some(x_bins);
+        Box::new(self)
+    }
+
+    pub fn y_bins(mut self, y_bins: Bins) -> Box<Histogram2d<X, Y>> {
+        self.y_bins = Some(y_bins);
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Box<Histogram2d<X, Y>> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn x_gap(mut self, x_gap: f64) -> Box<Histogram2d<X, Y>> {
+        self.x_gap = Some(x_gap);
+        Box::new(self)
+    }
+
+    pub fn y_gap(mut self, y_gap: f64) -> Box<Histogram2d<X, Y>> {
+        self.y_gap = Some(y_gap);
+        Box::new(self)
+    }
+
+    pub fn zsmooth(mut self, zsmooth: &str) -> Box<Histogram2d<X, Y>> {
+        self.zsmooth = Some(zsmooth.to_owned());
+        Box::new(self)
+    }
+
+    pub fn color_bar(mut self, color_bar: ColorBar) -> Box<Histogram2d<X, Y>> {
+        self.color_bar = Some(color_bar);
+        Box::new(self)
+    }
+
+    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Box<Histogram2d<X, Y>> {
+        self.auto_color_scale = Some(auto_color_scale);
+        Box::new(self)
+    }
+
+    pub fn color_scale(mut self, color_scale: ColorScale) -> Box<Histogram2d<X, Y>> {
+        self.color_scale = Some(color_scale);
+        Box::new(self)
+    }
+
+    pub fn show_scale(mut self, show_scale: bool) -> Box<Histogram2d<X, Y>> {
+        self.show_scale = Some(show_scale);
+        Box::new(self)
+    }
+
+    pub fn reverse_scale(mut self, reverse_scale: bool) -> Box<Histogram2d<X, Y>> {
+        self.reverse_scale = Some(reverse_scale);
+        Box::new(self)
+    }
+
+    pub fn zauto(mut self, zauto: bool) -> Box<Histogram2d<X, Y>> {
+        self.zauto = Some(zauto);
+        Box::new(self)
+    }
+
+    pub fn zhover_format(mut self, zhover_format: &str) -> Box<Histogram2d<X, Y>> {
+        self.zhover_format = Some(zhover_format.to_owned());
+        Box::new(self)
+    }
+
+    pub fn zmax(mut self, zmax: f64) -> Box<Histogram2d<X, Y>> {
+        self.zmax = Some(zmax);
+        Box::new(self)
+    }
+
+    pub fn zmid(mut self, zmid: f64) -> Box<Histogram2d<X, Y>> {
+        self.zmid = Some(zmid);
+        Box::new(self)
+    }
+
+    pub fn zmin(mut self, zmin: f64) -> Box<Histogram2d<X, Y>> {
+        self.zmin = Some(zmin);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Histogram2d<X, Y>> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn x_calendar(mut self, x_calendar: Calendar) -> Box<Histogram2d<X, Y>> {
+        self.x_calendar = Some(x_calendar);
+        Box::new(self)
+    }
+
+    pub fn y_calendar(mut self, y_calendar: Calendar) -> Box<Histogram2d<X, Y>> {
+        self.y_calendar = Some(y_calendar);
+        Box::new(self)
+    }
+
+    pub fn x_axis(mut self, x_axis: &str) -> Box<Histogram2d<X, Y>> {
+        self.x_axis = Some(x_axis.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, y_axis: &str) -> Box<Histogram2d<X, Y>> {
+        self.y_axis = Some(y_axis.to_owned());
+        Box::new(self)
+    }
+}
+
+impl<X, Y> Trace for Histogram2d<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Histogram2dContour<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    r#type: PlotType,
+    x: Vec<X>,
+    y: Vec<Y>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    z: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "histfunc")]
+    hist_func: Option<HistFunc>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "histnorm")]
+    hist_norm: Option<HistNorm>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nbinsx")]
+    n_bins_x: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nbinsy")]
+    n_bins_y: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autobinx")]
+    auto_bin_x: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autobiny")]
+    auto_bin_y: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bingroup")]
+    bin_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xbingroup")]
+    x_bin_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ybingroup")]
+    y_bin_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xbins")]
+    x_bins: Option<Bins>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ybins")]
+    y_bins: Option<Bins>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorbar")]
+    color_bar: Option<ColorBar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocolorscale")]
+    auto_color_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
+    color_scale: Option<ColorScale>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showscale")]
+    show_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "reversescale")]
+    reverse_scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zauto: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zhoverformat")]
+    zhover_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmax: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmid: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zmin: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autocontour")]
+    auto_contour: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ncontours")]
+    n_contours: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contours: Option<Contours>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<String>,
+}
+
+impl<X, Y> Histogram2dContour<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Box<Histogram2dContour<X, Y>> {
+        Box::new(Histogram2dContour {
+            r#type: PlotType::Histogram2dContour,
+            x,
+            y,
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            z: None,
+            hover_info: None,
+            hover_template: None,
+            hist_func: None,
+            hist_norm: None,
+            n_bins_x: None,
+            n_bins_y: None,
+            auto_bin_x: None,
+            auto_bin_y: None,
+            bin_group: None,
+            x_bin_group: None,
+            y_bin_group: None,
+            x_bins: None,
+            y_bins: None,
+            marker: None,
+            color_bar: None,
+            auto_color_scale: None,
+            color_scale: None,
+            show_scale: None,
+            reverse_scale: None,
+            zauto: None,
+            zhover_format: None,
+            zmax: None,
+            zmid: None,
+            zmin: None,
+            auto_contour: None,
+            n_contours: None,
+            contours: None,
+            line: None,
+            hover_label: None,
+            x_calendar: None,
+            y_calendar: None,
+            x_axis: None,
+            y_axis: None,
+        })
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Histogram2dContour<X, Y>> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<Histogram2dContour<X, Y>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Histogram2dContour<X, Y>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Histogram2dContour<X, Y>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Histogram2dContour<X, Y>> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Histogram2dContour<X, Y>> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Histogram2dContour<X, Y>> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn z(mut self, z: Vec<f64>) -> Box<Histogram2dContour<X, Y>> {
+        self.z = Some(z);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Histogram2dContour<X, Y>> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Histogram2dContour<X, Y>> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(
+        mut self,
+        hover_template: Vec<S>,
+    ) -> Box<Histogram2dContour<X, Y>> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn hist_func(mut self, hist_func: HistFunc) -> Box<Histogram2dContour<X, Y>> {
+        self.hist_func = Some(hist_func);
+        Box::new(self)
+    }
+
+    pub fn hist_norm(mut self, hist_norm: HistNorm) -> Box<Histogram2dContour<X, Y>> {
+        self.hist_norm = Some(hist_norm);
+        Box::new(self)
+    }
+
+    pub fn n_bins_x(mut self, n_bins_x: usize) -> Box<Histogram2dContour<X, Y>> {
+        self.n_bins_x = Some(n_bins_x);
+        Box::new(self)
+    }
+
+    pub fn n_bins_y(mut self, n_bins_y: usize) -> Box<Histogram2dContour<X, Y>> {
+        self.n_bins_y = Some(n_bins_y);
+        Box::new(self)
+    }
+
+    pub fn auto_bin_x(mut self, auto_bin_x: bool) -> Box<Histogram2dContour<X, Y>> {
+        self.auto_bin_x = Some(auto_bin_x);
+        Box::new(self)
+    }
+
+    pub fn auto_bin_y(mut self, auto_bin_y: bool) -> Box<Histogram2dContour<X, Y>> {
+        self.auto_bin_y = Some(auto_bin_y);
+        Box::new(self)
+    }
+
+    pub fn bin_group(mut self, bin_group: &str) -> Box<Histogram2dContour<X, Y>> {
+        self.bin_group = Some(bin_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn x_bin_group(mut self, x_bin_group: &str) -> Box<Histogram2dContour<X, Y>> {
+        self.x_bin_group = Some(x_bin_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_bin_group(mut self, y_bin_group: &str) -> Box<Histogram2dContour<X, Y>> {
+        self.y_bin_group = Some(y_bin_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn x_bins(mut self, x_bins: Bins) -> Box<Histogram2dContour<X, Y>> {
+        self.x_bins = Some(x_bins);
+        Box::new(self)
+    }
+
+    pub fn y_bins(mut self, y_bins: Bins) -> Box<Histogram2dContour<X, Y>> {
+        self.y_bins = Some(y_bins);
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Box<Histogram2dContour<X, Y>> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn color_bar(mut self, color_bar: ColorBar) -> Box<Histogram2dContour<X, Y>> {
+        self.color_bar = Some(color_bar);
+        Box::new(self)
+    }
+
+    pub fn auto_color_scale(mut self, auto_color_scale: bool) -> Box<Histogram2dContour<X, Y>> {
+        self.auto_color_scale = Some(auto_color_scale);
+        Box::new(self)
+    }
+
+    pub fn color_scale(mut self, color_scale: ColorScale) -> Box<Histogram2dContour<X, Y>> {
+        self.color_scale = Some(color_scale);
+        Box::new(self)
+    }
+
+    pub fn show_scale(mut self, show_scale: bool) -> Box<Histogram2dContour<X, Y>> {
+        self.show_scale = Some(show_scale);
+        Box::new(self)
+    }
+
+    pub fn reverse_scale(mut self, reverse_scale: bool) -> Box<Histogram2dContour<X, Y>> {
+        self.reverse_scale = Some(reverse_scale);
+        Box::new(self)
+    }
+
+    pub fn zauto(mut self, zauto: bool) -> Box<Histogram2dContour<X, Y>> {
+        self.zauto = Some(zauto);
+        Box::new(self)
+    }
+
+    pub fn zhover_format(mut self, zhover_format: &str) -> Box<Histogram2dContour<X, Y>> {
+        self.zhover_format = Some(zhover_format.to_owned());
+        Box::new(self)
+    }
+
+    pub fn zmax(mut self, zmax: f64) -> Box<Histogram2dContour<X, Y>> {
+        self.zmax = Some(zmax);
+        Box::new(self)
+    }
+
+    pub fn zmid(mut self, zmid: f64) -> Box<Histogram2dContour<X, Y>> {
+        self.zmid = Some(zmid);
+        Box::new(self)
+    }
+
+    pub fn zmin(mut self, zmin: f64) -> Box<Histogram2dContour<X, Y>> {
+        self.zmin = Some(zmin);
+        Box::new(self)
+    }
+
+    pub fn auto_contour(mut self, auto_contour: bool) -> Box<Histogram2dContour<X, Y>> {
+        self.auto_contour = Some(auto_contour);
+        Box::new(self)
+    }
+
+    pub fn n_contours(mut self, n_contours: usize) -> Box<Histogram2dContour<X, Y>> {
+        self.n_contours = Some(n_contours);
+        Box::new(self)
+    }
+
+    pub fn contours(mut self, contours: Contours) -> Box<Histogram2dContour<X, Y>> {
+        self.contours = Some(contours);
+        Box::new(self)
+    }
+
+    pub fn line(mut self, line: Line) -> Box<Histogram2dContour<X, Y>> {
+        self.line = Some(line);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Histogram2dContour<X, Y>> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn x_calendar(mut self, x_calendar: Calendar) -> Box<Histogram2dContour<X, Y>> {
+        self.x_calendar = Some(x_calendar);
+        Box::new(self)
+    }
+
+    pub fn y_calendar(mut self, y_calendar: Calendar) -> Box<Histogram2dContour<X, Y>> {
+        self.y_calendar = Some(y_calendar);
+        Box::new(self)
+    }
+
+    pub fn x_axis(mut self, x_axis: &str) -> Box<Histogram2dContour<X, Y>> {
+        self.x_axis = Some(x_axis.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, y_axis: &str) -> Box<Histogram2dContour<X, Y>> {
+        self.y_axis = Some(y_axis.to_owned());
+        Box::new(self)
+    }
+}
+
+impl<X, Y> Trace for Histogram2dContour<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contour::ContoursColoring;
+
+    #[test]
+    fn test_serialize_histogram2d() {
+        let trace = Histogram2d::new(vec![1, 2, 2], vec![3, 4, 4])
+            .hist_func(HistFunc::Sum)
+            .hist_norm(HistNorm::Percent)
+            .n_bins_x(10)
+            .x_bins(Bins::new(0.0, 5.0, 0.5))
+            .x_gap(1.0)
+            .zsmooth("best")
+            .x_axis("x2")
+            .y_axis("y2");
+        let expected = r#"{"type":"histogram2d","x":[1,2,2],"y":[3,4,4],"histfunc":"sum","histnorm":"percent","nbinsx":10,"xbins":{"start":0.0,"end":5.0,"size":0.5},"xgap":1.0,"zsmooth":"best","xaxis":"x2","yaxis":"y2"}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_histogram2d_contour() {
+        let trace = Histogram2dContour::new(vec![1, 2], vec![3, 4])
+            .hist_func(HistFunc::Count)
+            .n_bins_y(5)
+            .auto_contour(false)
+            .n_contours(8)
+            .contours(Contours::new().coloring(ContoursColoring::HeatMap))
+            .y_bins(Bins::new(0.0, 10.0, 2.0));
+        let expected = r#"{"type":"histogram2dcontour","x":[1,2],"y":[3,4],"histfunc":"count","nbinsy":5,"ybins":{"start":0.0,"end":10.0,"size":2.0},"autocontour":false,"ncontours":8,"contours":{"coloring":"heatmap"}}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
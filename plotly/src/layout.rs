@@ -1,13 +1,18 @@
+use crate::animation::{AnimationMode, AnimationOptions, FrameSettings, Transition};
 use crate::common::color::Color;
 use crate::common::{
-    Anchor, Calendar, ColorBar, ColorScale, DashType, Font, Label, Orientation, Side,
-    TickFormatStops, TickMode, Title,
+    Anchor, AxisReference, Calendar, ColorBar, ColorScale, DashType, Domain, Font, Label,
+    Orientation, Pad, Side, ThetaUnit, ThicknessMode, TickFormatStops, TickMode, Title,
 };
 use crate::plot::Trace;
 use crate::private;
-use serde::Serialize;
+use crate::template::Template;
+use crate::Error;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum AxisType {
     #[serde(rename = "-")]
     Default,
@@ -23,7 +28,7 @@ pub enum AxisType {
     MultiCategory,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum AxisConstrain {
     #[serde(rename = "range")]
     Range,
@@ -31,7 +36,7 @@ pub enum AxisConstrain {
     Domain,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ConstrainDirection {
     #[serde(rename = "left")]
     Left,
@@ -47,7 +52,7 @@ pub enum ConstrainDirection {
     Bottom,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum RangeMode {
     #[serde(rename = "normal")]
     Normal,
@@ -57,7 +62,7 @@ pub enum RangeMode {
     NonNegative,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum TicksDirection {
     #[serde(rename = "outside")]
     Outside,
@@ -65,7 +70,7 @@ pub enum TicksDirection {
     Inside,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum TicksPosition {
     #[serde(rename = "labels")]
     Labels,
@@ -73,7 +78,7 @@ pub enum TicksPosition {
     Boundaries,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ArrayShow {
     #[serde(rename = "all")]
     All,
@@ -85,7 +90,7 @@ pub enum ArrayShow {
     None,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum BarMode {
     #[serde(rename = "stack")]
     Stack,
@@ -97,7 +102,7 @@ pub enum BarMode {
     Relative,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum BarNorm {
     #[serde(rename = "")]
     Empty,
@@ -107,7 +112,7 @@ pub enum BarNorm {
     Percent,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum BoxMode {
     #[serde(rename = "group")]
     Group,
@@ -115,7 +120,7 @@ pub enum BoxMode {
     Overlay,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum ViolinMode {
     #[serde(rename = "group")]
     Group,
@@ -123,7 +128,7 @@ pub enum ViolinMode {
     Overlay,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum WaterfallMode {
     #[serde(rename = "group")]
     Group,
@@ -131,7 +136,8 @@ pub enum WaterfallMode {
     Overlay,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Legend {
     #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
     background_color: Option<String>,
@@ -270,7 +276,7 @@ impl Legend {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum Align {
     #[serde(rename = "top")]
     Top,
@@ -280,7 +286,8 @@ pub enum Align {
     Bottom,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Margin {
     #[serde(skip_serializing_if = "Option::is_none")]
     l: Option<usize>,
@@ -339,7 +346,8 @@ impl Margin {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct LayoutColorScale {
     #[serde(skip_serializing_if = "Option::is_none")]
     sequential: Option<ColorScale>,
@@ -374,7 +382,8 @@ impl LayoutColorScale {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct ColorAxis {
     #[serde(skip_serializing_if = "Option::is_none")]
     cauto: Option<bool>,
@@ -457,7 +466,348 @@ impl ColorAxis {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+pub enum RangeSliderRangeMode {
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "fixed")]
+    Fixed,
+    #[serde(rename = "match")]
+    Match,
+}
+
+/// How the range of a y axis is set within a `RangeSlider`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RangeSliderYAxis {
+    #[serde(skip_serializing_if = "Option::is_none", rename = "rangemode")]
+    range_mode: Option<RangeSliderRangeMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    range: Option<Vec<f64>>,
+}
+
+impl RangeSliderYAxis {
+    pub fn new() -> RangeSliderYAxis {
+        RangeSliderYAxis {
+            range_mode: None,
+            range: None,
+        }
+    }
+
+    pub fn range_mode(mut self, range_mode: RangeSliderRangeMode) -> RangeSliderYAxis {
+        self.range_mode = Some(range_mode);
+        self
+    }
+
+    pub fn range(mut self, range: Vec<f64>) -> RangeSliderYAxis {
+        self.range = Some(range);
+        self
+    }
+}
+
+/// A miniature of the whole plot below an x axis, used to select the visible range, set with
+/// `Axis::range_slider`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct RangeSlider {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thickness: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autorange")]
+    auto_range: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    range: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
+    background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bordercolor")]
+    border_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "borderwidth")]
+    border_width: Option<f64>,
+    // plotly.js names these after the y axes, i.e. "yaxis", "yaxis2", ...
+    #[serde(flatten, deserialize_with = "deserialize_range_slider_y_axes")]
+    y_axes: BTreeMap<String, RangeSliderYAxis>,
+}
+
+fn deserialize_range_slider_y_axes<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, RangeSliderYAxis>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    private::deserialize_indexed_map(deserializer, &["yaxis"])
+}
+
+impl RangeSlider {
+    pub fn new() -> RangeSlider {
+        RangeSlider {
+            visible: None,
+            thickness: None,
+            auto_range: None,
+            range: None,
+            background_color: None,
+            border_color: None,
+            border_width: None,
+            y_axes: BTreeMap::new(),
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> RangeSlider {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Height of the range slider as a fraction of the plot area.
+    pub fn thickness(mut self, thickness: f64) -> RangeSlider {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    pub fn auto_range(mut self, auto_range: bool) -> RangeSlider {
+        self.auto_range = Some(auto_range);
+        self
+    }
+
+    /// The full range covered by the range slider, as numbers, dates or categories.
+    pub fn range<V: Serialize>(mut self, range: Vec<V>) -> RangeSlider {
+        let range = range
+            .iter()
+            .map(|v| serde_json::to_value(v).unwrap())
+            .collect();
+        self.range = Some(range);
+        self
+    }
+
+    pub fn background_color<C: Color>(mut self, background_color: C) -> RangeSlider {
+        self.background_color = Some(background_color.to_color_string());
+        self
+    }
+
+    pub fn border_color<C: Color>(mut self, border_color: C) -> RangeSlider {
+        self.border_color = Some(border_color.to_color_string());
+        self
+    }
+
+    pub fn border_width(mut self, border_width: f64) -> RangeSlider {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    /// Set how the range of the y axis with the given id, e.g. `"y"` or `"y2"`, is set within the
+    /// range slider.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y_axis` is not a y axis id.
+    pub fn y_axis(mut self, y_axis: &str, range: RangeSliderYAxis) -> RangeSlider {
+        let name = match y_axis {
+            "y" | "y1" => "yaxis".to_owned(),
+            _ if private::is_indexed_name(y_axis, "y") => format!("yaxis{}", &y_axis[1..]),
+            _ => panic!("`{}` is not a y axis id such as \"y\" or \"y2\"", y_axis),
+        };
+        self.y_axes.insert(name, range);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum SelectorStep {
+    #[serde(rename = "month")]
+    Month,
+    #[serde(rename = "year")]
+    Year,
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "hour")]
+    Hour,
+    #[serde(rename = "minute")]
+    Minute,
+    #[serde(rename = "second")]
+    Second,
+    #[serde(rename = "all")]
+    All,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum StepMode {
+    #[serde(rename = "backward")]
+    Backward,
+    #[serde(rename = "todate")]
+    ToDate,
+}
+
+/// A button of a `RangeSelector`, selecting the last `count` `step`s, e.g. 6 months, or with
+/// `StepMode::ToDate` the period up to the last date rounded to the `step`, e.g. year to date.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct SelectorButton {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    step: Option<SelectorStep>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "stepmode")]
+    step_mode: Option<StepMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
+    template_item_name: Option<String>,
+}
+
+impl SelectorButton {
+    pub fn new() -> SelectorButton {
+        SelectorButton {
+            visible: None,
+            step: None,
+            step_mode: None,
+            count: None,
+            label: None,
+            name: None,
+            template_item_name: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> SelectorButton {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn step(mut self, step: SelectorStep) -> SelectorButton {
+        self.step = Some(step);
+        self
+    }
+
+    pub fn step_mode(mut self, step_mode: StepMode) -> SelectorButton {
+        self.step_mode = Some(step_mode);
+        self
+    }
+
+    pub fn count(mut self, count: f64) -> SelectorButton {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> SelectorButton {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> SelectorButton {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    pub fn template_item_name(mut self, template_item_name: &str) -> SelectorButton {
+        self.template_item_name = Some(template_item_name.to_owned());
+        self
+    }
+}
+
+/// Buttons above a date x axis selecting preset ranges, set with `Axis::range_selector`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RangeSelector {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    buttons: Option<Vec<SelectorButton>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xanchor")]
+    x_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yanchor")]
+    y_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
+    background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "activecolor")]
+    active_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bordercolor")]
+    border_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "borderwidth")]
+    border_width: Option<f64>,
+}
+
+impl RangeSelector {
+    pub fn new() -> RangeSelector {
+        RangeSelector {
+            visible: None,
+            buttons: None,
+            x: None,
+            x_anchor: None,
+            y: None,
+            y_anchor: None,
+            font: None,
+            background_color: None,
+            active_color: None,
+            border_color: None,
+            border_width: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> RangeSelector {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn buttons(mut self, buttons: Vec<SelectorButton>) -> RangeSelector {
+        self.buttons = Some(buttons);
+        self
+    }
+
+    pub fn x(mut self, x: f64) -> RangeSelector {
+        self.x = Some(x);
+        self
+    }
+
+    pub fn x_anchor(mut self, x_anchor: Anchor) -> RangeSelector {
+        self.x_anchor = Some(x_anchor);
+        self
+    }
+
+    pub fn y(mut self, y: f64) -> RangeSelector {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn y_anchor(mut self, y_anchor: Anchor) -> RangeSelector {
+        self.y_anchor = Some(y_anchor);
+        self
+    }
+
+    pub fn font(mut self, font: Font) -> RangeSelector {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn background_color<C: Color>(mut self, background_color: C) -> RangeSelector {
+        self.background_color = Some(background_color.to_color_string());
+        self
+    }
+
+    pub fn active_color<C: Color>(mut self, active_color: C) -> RangeSelector {
+        self.active_color = Some(active_color.to_color_string());
+        self
+    }
+
+    pub fn border_color<C: Color>(mut self, border_color: C) -> RangeSelector {
+        self.border_color = Some(border_color.to_color_string());
+        self
+    }
+
+    pub fn border_width(mut self, border_width: f64) -> RangeSelector {
+        self.border_width = Some(border_width);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Axis {
     #[serde(skip_serializing_if = "Option::is_none")]
     visible: Option<bool>,
@@ -467,12 +817,12 @@ pub struct Axis {
     title: Option<Title>,
     #[serde(skip_serializing_if = "Option::is_none")]
     r#type: Option<AxisType>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "auto_range")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autorange")]
     auto_range: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "rangemode")]
     range_mode: Option<RangeMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    range: Option<Vec<f64>>,
+    range: Option<Vec<serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "fixedrange")]
     fixed_range: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -491,7 +841,7 @@ pub struct Axis {
 
     #[serde(skip_serializing_if = "Option::is_none", rename = "tickvals")]
     tick_values: Option<Vec<f64>>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "tick_text")]
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticktext")]
     tick_text: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     ticks: Option<TicksDirection>,
@@ -576,6 +926,16 @@ pub struct Axis {
     #[serde(skip_serializing_if = "Option::is_none")]
     position: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    anchor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overlaying: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    matches: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "rangeslider")]
+    range_slider: Option<RangeSlider>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "rangeselector")]
+    range_selector: Option<RangeSelector>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     calendar: Option<Calendar>,
 }
 
@@ -639,6 +999,11 @@ impl Axis {
             side: None,
             domain: None,
             position: None,
+            anchor: None,
+            overlaying: None,
+            matches: None,
+            range_slider: None,
+            range_selector: None,
             calendar: None,
         }
     }
@@ -673,7 +1038,12 @@ impl Axis {
         self
     }
 
-    pub fn range(mut self, range: Vec<f64>) -> Axis {
+    /// Set the range of the axis; dates for date axes, numbers otherwise.
+    pub fn range<V: Serialize>(mut self, range: Vec<V>) -> Axis {
+        let range = range
+            .iter()
+            .map(|v| serde_json::to_value(v).unwrap())
+            .collect();
         self.range = Some(range);
         self
     }
@@ -928,13 +1298,44 @@ impl Axis {
         self
     }
 
+    pub fn anchor(mut self, anchor: &str) -> Axis {
+        self.anchor = Some(anchor.to_owned());
+        self
+    }
+
+    pub fn overlaying(mut self, overlaying: &str) -> Axis {
+        self.overlaying = Some(overlaying.to_owned());
+        self
+    }
+
+    pub fn matches(mut self, matches: &str) -> Axis {
+        self.matches = Some(matches.to_owned());
+        self
+    }
+
+    pub fn range_slider(mut self, range_slider: RangeSlider) -> Axis {
+        self.range_slider = Some(range_slider);
+        self
+    }
+
+    pub fn range_selector(mut self, range_selector: RangeSelector) -> Axis {
+        self.range_selector = Some(range_selector);
+        self
+    }
+
     pub fn calendar(mut self, calendar: Calendar) -> Axis {
         self.calendar = Some(calendar);
         self
     }
 }
 
-#[derive(Serialize, Debug)]
+impl Default for Axis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
 pub enum RowOrder {
     #[serde(rename="top to bottom")]
     TopToBottom,
@@ -942,15 +1343,15 @@ pub enum RowOrder {
     BottomToTop,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum GridPattern {
     #[serde(rename="independent")]
     Independent,
-    #[serde(rename="independent")]
+    #[serde(rename="coupled")]
     Coupled,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum GridXSide {
     #[serde(rename="bottom")]
     Bottom,
@@ -962,7 +1363,7 @@ pub enum GridXSide {
     Top,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 pub enum GridYSide {
     #[serde(rename="left")]
     Left,
@@ -974,7 +1375,8 @@ pub enum GridYSide {
     Right,
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct GridDomain {
     #[serde(skip_serializing_if = "Option::is_none")]
     x: Option<Vec<f64>>,
@@ -998,7 +1400,14 @@ impl GridDomain {
     }
 }
 
-#[derive(Serialize, Debug)]
+impl Default for GridDomain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct LayoutGrid {
     #[serde(skip_serializing_if = "Option::is_none")]
     rows: Option<usize>,
@@ -1007,7 +1416,7 @@ pub struct LayoutGrid {
     #[serde(skip_serializing_if = "Option::is_none")]
     columns: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none", rename="subplots")]
-    sub_plots: Option<Vec<String>>,
+    sub_plots: Option<Vec<Vec<String>>>,
     #[serde(skip_serializing_if = "Option::is_none", rename="xaxes")]
     x_axes: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none", rename="yaxes")]
@@ -1059,7 +1468,9 @@ impl LayoutGrid {
         self
     }
 
-    pub fn sub_plots(mut self, sub_plots: Vec<String>) -> LayoutGrid {
+    /// The cartesian subplot in each cell, given row by row as ids like "xy" or "x2y3", or "" for
+    /// cells without one.
+    pub fn sub_plots(mut self, sub_plots: Vec<Vec<String>>) -> LayoutGrid {
         self.sub_plots = Some(sub_plots);
         self
     }
@@ -1104,653 +1515,3301 @@ impl LayoutGrid {
     }
 }
 
-#[derive(Serialize, Debug)]
-pub struct Layout {
+#[derive(Serialize, Deserialize, Debug)]
+pub enum PolarDirection {
+    #[serde(rename = "clockwise")]
+    Clockwise,
+    #[serde(rename = "counterclockwise")]
+    CounterClockwise,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum GridShape {
+    #[serde(rename = "circular")]
+    Circular,
+    #[serde(rename = "linear")]
+    Linear,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct RadialAxis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     title: Option<Title>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
-    show_legend: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    legend: Option<Legend>,
+    r#type: Option<AxisType>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autorange")]
+    auto_range: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "rangemode")]
+    range_mode: Option<RangeMode>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    margin: Option<Margin>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "autosize")]
-    auto_size: Option<bool>,
+    range: Option<Vec<f64>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    width: Option<usize>,
+    angle: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    height: Option<usize>,
+    side: Option<PolarDirection>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickmode")]
+    tick_mode: Option<TickMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nticks")]
+    n_ticks: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    font: Option<Font>,
-
-    // uniform_text: Option<UniformText>,
+    tick0: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    separators: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "paper_bgcolor")]
-    paper_background_color: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "plot_bgcolor")]
+    dtick: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ticks: Option<TicksDirection>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticklen")]
+    tick_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickwidth")]
+    tick_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickcolor")]
+    tick_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickangle")]
+    tick_angle: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickprefix")]
+    tick_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverformat")]
+    hover_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linecolor")]
+    line_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linewidth")]
+    line_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridcolor")]
+    grid_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridwidth")]
+    grid_width: Option<usize>,
+}
+
+impl RadialAxis {
+    pub fn new() -> RadialAxis {
+        RadialAxis {
+            visible: None,
+            color: None,
+            title: None,
+            r#type: None,
+            auto_range: None,
+            range_mode: None,
+            range: None,
+            angle: None,
+            side: None,
+            tick_mode: None,
+            n_ticks: None,
+            tick0: None,
+            dtick: None,
+            tick_values: None,
+            tick_text: None,
+            ticks: None,
+            tick_length: None,
+            tick_width: None,
+            tick_color: None,
+            show_tick_labels: None,
+            tick_font: None,
+            tick_angle: None,
+            tick_prefix: None,
+            tick_suffix: None,
+            tick_format: None,
+            hover_format: None,
+            show_line: None,
+            line_color: None,
+            line_width: None,
+            show_grid: None,
+            grid_color: None,
+            grid_width: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> RadialAxis {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> RadialAxis {
+        self.color = Some(color.to_color_string());
+        self
+    }
+
+    pub fn title(mut self, title: Title) -> RadialAxis {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn type_(mut self, t: AxisType) -> RadialAxis {
+        self.r#type = Some(t);
+        self
+    }
+
+    pub fn auto_range(mut self, auto_range: bool) -> RadialAxis {
+        self.auto_range = Some(auto_range);
+        self
+    }
+
+    pub fn range_mode(mut self, range_mode: RangeMode) -> RadialAxis {
+        self.range_mode = Some(range_mode);
+        self
+    }
+
+    pub fn range(mut self, range: Vec<f64>) -> RadialAxis {
+        self.range = Some(range);
+        self
+    }
+
+    pub fn angle(mut self, angle: f64) -> RadialAxis {
+        self.angle = Some(angle);
+        self
+    }
+
+    pub fn side(mut self, side: PolarDirection) -> RadialAxis {
+        self.side = Some(side);
+        self
+    }
+
+    pub fn tick_mode(mut self, tick_mode: TickMode) -> RadialAxis {
+        self.tick_mode = Some(tick_mode);
+        self
+    }
+
+    pub fn n_ticks(mut self, n_ticks: usize) -> RadialAxis {
+        self.n_ticks = Some(n_ticks);
+        self
+    }
+
+    pub fn tick0(mut self, tick0: f64) -> RadialAxis {
+        self.tick0 = Some(tick0);
+        self
+    }
+
+    pub fn dtick(mut self, dtick: f64) -> RadialAxis {
+        self.dtick = Some(dtick);
+        self
+    }
+
+    pub fn tick_values(mut self, tick_values: Vec<f64>) -> RadialAxis {
+        self.tick_values = Some(tick_values);
+        self
+    }
+
+    pub fn tick_text<S: AsRef<str>>(mut self, tick_text: Vec<S>) -> RadialAxis {
+        let tick_text = private::owned_string_vector(tick_text);
+        self.tick_text = Some(tick_text);
+        self
+    }
+
+    pub fn ticks(mut self, ticks: TicksDirection) -> RadialAxis {
+        self.ticks = Some(ticks);
+        self
+    }
+
+    pub fn tick_length(mut self, tick_length: usize) -> RadialAxis {
+        self.tick_length = Some(tick_length);
+        self
+    }
+
+    pub fn tick_width(mut self, tick_width: usize) -> RadialAxis {
+        self.tick_width = Some(tick_width);
+        self
+    }
+
+    pub fn tick_color<C: Color>(mut self, tick_color: C) -> RadialAxis {
+        self.tick_color = Some(tick_color.to_color_string());
+        self
+    }
+
+    pub fn show_tick_labels(mut self, show_tick_labels: bool) -> RadialAxis {
+        self.show_tick_labels = Some(show_tick_labels);
+        self
+    }
+
+    pub fn tick_font(mut self, tick_font: Font) -> RadialAxis {
+        self.tick_font = Some(tick_font);
+        self
+    }
+
+    pub fn tick_angle(mut self, tick_angle: f64) -> RadialAxis {
+        self.tick_angle = Some(tick_angle);
+        self
+    }
+
+    pub fn tick_prefix(mut self, tick_prefix: &str) -> RadialAxis {
+        self.tick_prefix = Some(tick_prefix.to_owned());
+        self
+    }
+
+    pub fn tick_suffix(mut self, tick_suffix: &str) -> RadialAxis {
+        self.tick_suffix = Some(tick_suffix.to_owned());
+        self
+    }
+
+    pub fn tick_format(mut self, tick_format: &str) -> RadialAxis {
+        self.tick_format = Some(tick_format.to_owned());
+        self
+    }
+
+    pub fn hover_format(mut self, hover_format: &str) -> RadialAxis {
+        self.hover_format = Some(hover_format.to_owned());
+        self
+    }
+
+    pub fn show_line(mut self, show_line: bool) -> RadialAxis {
+        self.show_line = Some(show_line);
+        self
+    }
+
+    pub fn line_color<C: Color>(mut self, line_color: C) -> RadialAxis {
+        self.line_color = Some(line_color.to_color_string());
+        self
+    }
+
+    pub fn line_width(mut self, line_width: usize) -> RadialAxis {
+        self.line_width = Some(line_width);
+        self
+    }
+
+    pub fn show_grid(mut self, show_grid: bool) -> RadialAxis {
+        self.show_grid = Some(show_grid);
+        self
+    }
+
+    pub fn grid_color<C: Color>(mut self, grid_color: C) -> RadialAxis {
+        self.grid_color = Some(grid_color.to_color_string());
+        self
+    }
+
+    pub fn grid_width(mut self, grid_width: usize) -> RadialAxis {
+        self.grid_width = Some(grid_width);
+        self
+    }
+}
+
+impl Default for RadialAxis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct AngularAxis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#type: Option<AxisType>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "thetaunit")]
+    theta_unit: Option<ThetaUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    period: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<PolarDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickmode")]
+    tick_mode: Option<TickMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "nticks")]
+    n_ticks: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tick0: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dtick: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickvals")]
+    tick_values: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticktext")]
+    tick_text: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ticks: Option<TicksDirection>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticklen")]
+    tick_length: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickwidth")]
+    tick_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickcolor")]
+    tick_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showticklabels")]
+    show_tick_labels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickfont")]
+    tick_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickangle")]
+    tick_angle: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickprefix")]
+    tick_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticksuffix")]
+    tick_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickformat")]
+    tick_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverformat")]
+    hover_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showline")]
+    show_line: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linecolor")]
+    line_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "linewidth")]
+    line_width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showgrid")]
+    show_grid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridcolor")]
+    grid_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridwidth")]
+    grid_width: Option<usize>,
+}
+
+impl AngularAxis {
+    pub fn new() -> AngularAxis {
+        AngularAxis {
+            visible: None,
+            color: None,
+            r#type: None,
+            theta_unit: None,
+            period: None,
+            direction: None,
+            rotation: None,
+            tick_mode: None,
+            n_ticks: None,
+            tick0: None,
+            dtick: None,
+            tick_values: None,
+            tick_text: None,
+            ticks: None,
+            tick_length: None,
+            tick_width: None,
+            tick_color: None,
+            show_tick_labels: None,
+            tick_font: None,
+            tick_angle: None,
+            tick_prefix: None,
+            tick_suffix: None,
+            tick_format: None,
+            hover_format: None,
+            show_line: None,
+            line_color: None,
+            line_width: None,
+            show_grid: None,
+            grid_color: None,
+            grid_width: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> AngularAxis {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> AngularAxis {
+        self.color = Some(color.to_color_string());
+        self
+    }
+
+    pub fn type_(mut self, t: AxisType) -> AngularAxis {
+        self.r#type = Some(t);
+        self
+    }
+
+    pub fn theta_unit(mut self, theta_unit: ThetaUnit) -> AngularAxis {
+        self.theta_unit = Some(theta_unit);
+        self
+    }
+
+    pub fn period(mut self, period: usize) -> AngularAxis {
+        self.period = Some(period);
+        self
+    }
+
+    pub fn direction(mut self, direction: PolarDirection) -> AngularAxis {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn rotation(mut self, rotation: f64) -> AngularAxis {
+        self.rotation = Some(rotation);
+        self
+    }
+
+    pub fn tick_mode(mut self, tick_mode: TickMode) -> AngularAxis {
+        self.tick_mode = Some(tick_mode);
+        self
+    }
+
+    pub fn n_ticks(mut self, n_ticks: usize) -> AngularAxis {
+        self.n_ticks = Some(n_ticks);
+        self
+    }
+
+    pub fn tick0(mut self, tick0: f64) -> AngularAxis {
+        self.tick0 = Some(tick0);
+        self
+    }
+
+    pub fn dtick(mut self, dtick: f64) -> AngularAxis {
+        self.dtick = Some(dtick);
+        self
+    }
+
+    pub fn tick_values(mut self, tick_values: Vec<f64>) -> AngularAxis {
+        self.tick_values = Some(tick_values);
+        self
+    }
+
+    pub fn tick_text<S: AsRef<str>>(mut self, tick_text: Vec<S>) -> AngularAxis {
+        let tick_text = private::owned_string_vector(tick_text);
+        self.tick_text = Some(tick_text);
+        self
+    }
+
+    pub fn ticks(mut self, ticks: TicksDirection) -> AngularAxis {
+        self.ticks = Some(ticks);
+        self
+    }
+
+    pub fn tick_length(mut self, tick_length: usize) -> AngularAxis {
+        self.tick_length = Some(tick_length);
+        self
+    }
+
+    pub fn tick_width(mut self, tick_width: usize) -> AngularAxis {
+        self.tick_width = Some(tick_width);
+        self
+    }
+
+    pub fn tick_color<C: Color>(mut self, tick_color: C) -> AngularAxis {
+        self.tick_color = Some(tick_color.to_color_string());
+        self
+    }
+
+    pub fn show_tick_labels(mut self, show_tick_labels: bool) -> AngularAxis {
+        self.show_tick_labels = Some(show_tick_labels);
+        self
+    }
+
+    pub fn tick_font(mut self, tick_font: Font) -> AngularAxis {
+        self.tick_font = Some(tick_font);
+        self
+    }
+
+    pub fn tick_angle(mut self, tick_angle: f64) -> AngularAxis {
+        self.tick_angle = Some(tick_angle);
+        self
+    }
+
+    pub fn tick_prefix(mut self, tick_prefix: &str) -> AngularAxis {
+        self.tick_prefix = Some(tick_prefix.to_owned());
+        self
+    }
+
+    pub fn tick_suffix(mut self, tick_suffix: &str) -> AngularAxis {
+        self.tick_suffix = Some(tick_suffix.to_owned());
+        self
+    }
+
+    pub fn tick_format(mut self, tick_format: &str) -> AngularAxis {
+        self.tick_format = Some(tick_format.to_owned());
+        self
+    }
+
+    pub fn hover_format(mut self, hover_format: &str) -> AngularAxis {
+        self.hover_format = Some(hover_format.to_owned());
+        self
+    }
+
+    pub fn show_line(mut self, show_line: bool) -> AngularAxis {
+        self.show_line = Some(show_line);
+        self
+    }
+
+    pub fn line_color<C: Color>(mut self, line_color: C) -> AngularAxis {
+        self.line_color = Some(line_color.to_color_string());
+        self
+    }
+
+    pub fn line_width(mut self, line_width: usize) -> AngularAxis {
+        self.line_width = Some(line_width);
+        self
+    }
+
+    pub fn show_grid(mut self, show_grid: bool) -> AngularAxis {
+        self.show_grid = Some(show_grid);
+        self
+    }
+
+    pub fn grid_color<C: Color>(mut self, grid_color: C) -> AngularAxis {
+        self.grid_color = Some(grid_color.to_color_string());
+        self
+    }
+
+    pub fn grid_width(mut self, grid_width: usize) -> AngularAxis {
+        self.grid_width = Some(grid_width);
+        self
+    }
+}
+
+impl Default for AngularAxis {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct LayoutPolar {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sector: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hole: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
+    background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "radialaxis")]
+    radial_axis: Option<RadialAxis>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "angularaxis")]
+    angular_axis: Option<AngularAxis>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "gridshape")]
+    grid_shape: Option<GridShape>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "barmode")]
+    bar_mode: Option<BarMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bargap")]
+    bar_gap: Option<f64>,
+}
+
+impl LayoutPolar {
+    pub fn new() -> LayoutPolar {
+        LayoutPolar {
+            domain: None,
+            sector: None,
+            hole: None,
+            background_color: None,
+            radial_axis: None,
+            angular_axis: None,
+            grid_shape: None,
+            bar_mode: None,
+            bar_gap: None,
+        }
+    }
+
+    pub fn domain(mut self, domain: Domain) -> LayoutPolar {
+        self.domain = Some(domain);
+        self
+    }
+
+    pub fn sector(mut self, sector: Vec<f64>) -> LayoutPolar {
+        self.sector = Some(sector);
+        self
+    }
+
+    pub fn hole(mut self, hole: f64) -> LayoutPolar {
+        self.hole = Some(hole);
+        self
+    }
+
+    pub fn background_color<C: Color>(mut self, background_color: C) -> LayoutPolar {
+        self.background_color = Some(background_color.to_color_string());
+        self
+    }
+
+    pub fn radial_axis(mut self, radial_axis: RadialAxis) -> LayoutPolar {
+        self.radial_axis = Some(radial_axis);
+        self
+    }
+
+    pub fn angular_axis(mut self, angular_axis: AngularAxis) -> LayoutPolar {
+        self.angular_axis = Some(angular_axis);
+        self
+    }
+
+    pub fn grid_shape(mut self, grid_shape: GridShape) -> LayoutPolar {
+        self.grid_shape = Some(grid_shape);
+        self
+    }
+
+    pub fn bar_mode(mut self, bar_mode: BarMode) -> LayoutPolar {
+        self.bar_mode = Some(bar_mode);
+        self
+    }
+
+    pub fn bar_gap(mut self, bar_gap: f64) -> LayoutPolar {
+        self.bar_gap = Some(bar_gap);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ProjectionType {
+    #[serde(rename = "perspective")]
+    Perspective,
+    #[serde(rename = "orthographic")]
+    Orthographic,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct CameraProjection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#type: Option<ProjectionType>,
+}
+
+impl CameraProjection {
+    pub fn new() -> CameraProjection {
+        CameraProjection { r#type: None }
+    }
+
+    pub fn type_(mut self, t: ProjectionType) -> CameraProjection {
+        self.r#type = Some(t);
+        self
+    }
+}
+
+impl Default for CameraProjection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Eye {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Eye {
+    pub fn new(x: f64, y: f64, z: f64) -> Eye {
+        Eye { x, y, z }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct CameraCenter {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl CameraCenter {
+    pub fn new(x: f64, y: f64, z: f64) -> CameraCenter {
+        CameraCenter { x, y, z }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Up {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Up {
+    pub fn new(x: f64, y: f64, z: f64) -> Up {
+        Up { x, y, z }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Camera {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eye: Option<Eye>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    center: Option<CameraCenter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    up: Option<Up>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    projection: Option<CameraProjection>,
+}
+
+impl Camera {
+    pub fn new() -> Camera {
+        Camera {
+            eye: None,
+            center: None,
+            up: None,
+            projection: None,
+        }
+    }
+
+    pub fn eye(mut self, eye: Eye) -> Camera {
+        self.eye = Some(eye);
+        self
+    }
+
+    pub fn center(mut self, center: CameraCenter) -> Camera {
+        self.center = Some(center);
+        self
+    }
+
+    pub fn up(mut self, up: Up) -> Camera {
+        self.up = Some(up);
+        self
+    }
+
+    pub fn projection(mut self, projection: CameraProjection) -> Camera {
+        self.projection = Some(projection);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum AspectMode {
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "cube")]
+    Cube,
+    #[serde(rename = "data")]
+    Data,
+    #[serde(rename = "manual")]
+    Manual,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct AspectRatio {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl AspectRatio {
+    pub fn new(x: f64, y: f64, z: f64) -> AspectRatio {
+        AspectRatio { x, y, z }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum DragMode3D {
+    #[serde(rename = "orbit")]
+    Orbit,
+    #[serde(rename = "turntable")]
+    Turntable,
+    #[serde(rename = "zoom")]
+    Zoom,
+    #[serde(rename = "pan")]
+    Pan,
+    #[serde(rename = "false")]
+    False,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum HoverMode3D {
+    #[serde(rename = "closest")]
+    Closest,
+    #[serde(rename = "false")]
+    False,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct LayoutScene {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
+    background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    camera: Option<Camera>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "aspectmode")]
+    aspect_mode: Option<AspectMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "aspectratio")]
+    aspect_ratio: Option<AspectRatio>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    xaxis: Option<Axis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    yaxis: Option<Axis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zaxis: Option<Axis>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "dragmode")]
+    drag_mode: Option<private::TruthyEnum<DragMode3D>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovermode")]
+    hover_mode: Option<private::TruthyEnum<HoverMode3D>>,
+}
+
+impl LayoutScene {
+    pub fn new() -> LayoutScene {
+        LayoutScene {
+            domain: None,
+            background_color: None,
+            camera: None,
+            aspect_mode: None,
+            aspect_ratio: None,
+            xaxis: None,
+            yaxis: None,
+            zaxis: None,
+            drag_mode: None,
+            hover_mode: None,
+        }
+    }
+
+    pub fn domain(mut self, domain: Domain) -> LayoutScene {
+        self.domain = Some(domain);
+        self
+    }
+
+    pub fn background_color<C: Color>(mut self, background_color: C) -> LayoutScene {
+        self.background_color = Some(background_color.to_color_string());
+        self
+    }
+
+    pub fn camera(mut self, camera: Camera) -> LayoutScene {
+        self.camera = Some(camera);
+        self
+    }
+
+    pub fn aspect_mode(mut self, aspect_mode: AspectMode) -> LayoutScene {
+        self.aspect_mode = Some(aspect_mode);
+        self
+    }
+
+    pub fn aspect_ratio(mut self, aspect_ratio: AspectRatio) -> LayoutScene {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
+    pub fn xaxis(mut self, xaxis: Axis) -> LayoutScene {
+        self.xaxis = Some(xaxis);
+        self
+    }
+
+    pub fn yaxis(mut self, yaxis: Axis) -> LayoutScene {
+        self.yaxis = Some(yaxis);
+        self
+    }
+
+    pub fn zaxis(mut self, zaxis: Axis) -> LayoutScene {
+        self.zaxis = Some(zaxis);
+        self
+    }
+
+    pub fn drag_mode(mut self, drag_mode: DragMode3D) -> LayoutScene {
+        self.drag_mode = Some(private::TruthyEnum { e: drag_mode });
+        self
+    }
+
+    pub fn hover_mode(mut self, hover_mode: HoverMode3D) -> LayoutScene {
+        self.hover_mode = Some(private::TruthyEnum { e: hover_mode });
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Layout {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<Title>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    legend: Option<Legend>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    margin: Option<Margin>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "autosize")]
+    auto_size: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font: Option<Font>,
+
+    // uniform_text: Option<UniformText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    separators: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "paper_bgcolor")]
+    paper_background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "plot_bgcolor")]
     plot_background_color: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "colorscale")]
     color_scale: Option<LayoutColorScale>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    colorway: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "coloraxis")]
-    color_axis: Option<ColorAxis>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "hovermode")]
-    // mode_bar: Option<ModeBar>,
-    hover_mode: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "clickmode")]
-    click_mode: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "dragmode")]
-    drag_mode: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "selectdirection")]
-    select_direction: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverdistance")]
-    hover_distance: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "spikedistance")]
-    spike_distance: Option<i32>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
-    hover_label: Option<Label>,
+    colorway: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "coloraxis")]
+    color_axis: Option<ColorAxis>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovermode")]
+    // mode_bar: Option<ModeBar>,
+    hover_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "clickmode")]
+    click_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "dragmode")]
+    drag_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "selectdirection")]
+    select_direction: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverdistance")]
+    hover_distance: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "spikedistance")]
+    spike_distance: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<Template>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    grid: Option<LayoutGrid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    xaxis: Option<Axis>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    yaxis: Option<Axis>,
+    // The scene and polar subplots are boxed to keep `Layout` small, otherwise deserializing it
+    // overflows the stack in debug builds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scene: Option<Box<LayoutScene>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    polar: Option<Box<LayoutPolar>>,
+    // plotly.js numbers the additional axes and subplots, i.e. "xaxis2", "scene3", ... Each of these
+    // flattened maps sees all the remaining attributes and keeps those with its own prefixes.
+    #[serde(flatten, deserialize_with = "deserialize_layout_axes")]
+    axes: BTreeMap<String, Axis>,
+    #[serde(flatten, deserialize_with = "deserialize_layout_scenes")]
+    scenes: BTreeMap<String, LayoutScene>,
+    #[serde(flatten, deserialize_with = "deserialize_layout_polars")]
+    polars: BTreeMap<String, LayoutPolar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    annotations: Option<Vec<Annotation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shapes: Option<Vec<Shape>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "updatemenus")]
+    update_menus: Option<Vec<UpdateMenu>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sliders: Option<Vec<Slider>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    images: Option<Vec<LayoutImage>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "boxmode")]
+    box_mode: Option<BoxMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "boxgap")]
+    box_gap: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "boxgroupgap")]
+    box_group_gap: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", rename = "barmode")]
+    bar_mode: Option<BarMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "barnorm")]
+    bar_norm: Option<BarNorm>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bargap")]
+    bar_gap: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bargroupgap")]
+    bar_group_gap: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", rename = "violinmode")]
+    violin_mode: Option<ViolinMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "violingap")]
+    violin_gap: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "violingroupgap")]
+    violin_group_gap: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", rename = "waterfallmode")]
+    waterfall_mode: Option<WaterfallMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "waterfallgap")]
+    waterfall_gap: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "waterfallgroupgap")]
+    waterfall_group_gap: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none", rename = "piecolorway")]
+    pie_colorway: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "extendpiecolors")]
+    extend_pie_colors: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sunburstcolorway")]
+    sunburst_colorway: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "extendsunburstcolors")]
+    extend_sunburst_colors: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none", rename = "treemapcolorway")]
+    treemap_colorway: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "extendtreemapcolors")]
+    extend_treemap_colors: Option<bool>,
+}
+
+const LAYOUT_AXES: [&str; 2] = ["xaxis", "yaxis"];
+const LAYOUT_SUBPLOTS: [&str; 4] = ["xaxis", "yaxis", "scene", "polar"];
+
+fn deserialize_layout_axes<'de, D>(deserializer: D) -> Result<BTreeMap<String, Axis>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    private::deserialize_indexed_map_among(deserializer, &LAYOUT_AXES, &LAYOUT_SUBPLOTS)
+}
+
+fn deserialize_layout_scenes<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, LayoutScene>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    private::deserialize_indexed_map_among(deserializer, &["scene"], &LAYOUT_SUBPLOTS)
+}
+
+fn deserialize_layout_polars<'de, D>(
+    deserializer: D,
+) -> Result<BTreeMap<String, LayoutPolar>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    private::deserialize_indexed_map_among(deserializer, &["polar"], &LAYOUT_SUBPLOTS)
+}
+
+/// Returns the plotly.js name of the `n`th subplot with the given prefix, e.g. "xaxis" for the
+/// first x axis and "xaxis2" for the second.
+fn indexed_name(prefix: &str, n: usize) -> String {
+    assert!(n >= 1, "{} indices start at 1", prefix);
+    if n == 1 {
+        prefix.to_owned()
+    } else {
+        format!("{}{}", prefix, n)
+    }
+}
+
+impl Layout {
+    pub fn new() -> Layout {
+        Layout {
+            title: None,
+            show_legend: None,
+            legend: None,
+            margin: None,
+            auto_size: None,
+            width: None,
+            height: None,
+            font: None,
+            separators: None,
+            paper_background_color: None,
+            plot_background_color: None,
+            color_scale: None,
+            colorway: None,
+            color_axis: None,
+            hover_mode: None,
+            click_mode: None,
+            drag_mode: None,
+            select_direction: None,
+            hover_distance: None,
+            spike_distance: None,
+            hover_label: None,
+            grid: None,
+            calendar: None,
+            xaxis: None,
+            yaxis: None,
+            scene: None,
+            polar: None,
+            axes: BTreeMap::new(),
+            scenes: BTreeMap::new(),
+            polars: BTreeMap::new(),
+            template: None,
+            annotations: None,
+            shapes: None,
+            update_menus: None,
+            sliders: None,
+            images: None,
+            box_mode: None,
+            box_gap: None,
+            box_group_gap: None,
+            bar_mode: None,
+            bar_norm: None,
+            bar_gap: None,
+            bar_group_gap: None,
+
+            violin_mode: None,
+            violin_gap: None,
+            violin_group_gap: None,
+
+            waterfall_mode: None,
+            waterfall_gap: None,
+            waterfall_group_gap: None,
+
+            pie_colorway: None,
+            extend_pie_colors: None,
+
+            sunburst_colorway: None,
+            extend_sunburst_colors: None,
+
+            treemap_colorway: None,
+            extend_treemap_colors: None,
+        }
+    }
+
+    pub fn title(mut self, title: Title) -> Layout {
+        self.title = Some(title);
+        self
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Layout {
+        self.show_legend = Some(show_legend);
+        self
+    }
+
+    pub fn legend(mut self, legend: Legend) -> Layout {
+        self.legend = Some(legend);
+        self
+    }
+
+    pub fn margin(mut self, margin: Margin) -> Layout {
+        self.margin = Some(margin);
+        self
+    }
+
+    pub fn auto_size(mut self, auto_size: bool) -> Layout {
+        self.auto_size = Some(auto_size);
+        self
+    }
+
+    pub fn width(mut self, width: usize) -> Layout {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: usize) -> Layout {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn font(mut self, font: Font) -> Layout {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn separators(mut self, separators: &str) -> Layout {
+        self.separators = Some(separators.to_owned());
+        self
+    }
+
+    pub fn paper_background_color<C: Color>(mut self, paper_background_color: C) -> Layout {
+        self.paper_background_color = Some(paper_background_color.to_color_string());
+        self
+    }
+
+    pub fn plot_background_color<C: Color>(mut self, plot_background_color: C) -> Layout {
+        self.plot_background_color = Some(plot_background_color.to_color_string());
+        self
+    }
+
+    pub fn color_scale(mut self, color_scale: LayoutColorScale) -> Layout {
+        self.color_scale = Some(color_scale);
+        self
+    }
+
+    pub fn colorway<C: Color>(mut self, colorway: Vec<C>) -> Layout {
+        let colorway = private::to_color_array(colorway);
+        self.colorway = Some(colorway);
+        self
+    }
+
+    pub fn color_axis(mut self, color_axis: ColorAxis) -> Layout {
+        self.color_axis = Some(color_axis);
+        self
+    }
+
+    pub fn hover_mode(mut self, hover_mode: &str) -> Layout {
+        self.hover_mode = Some(hover_mode.to_owned());
+        self
+    }
+
+    pub fn click_mode(mut self, click_mode: &str) -> Layout {
+        self.click_mode = Some(click_mode.to_owned());
+        self
+    }
+
+    pub fn drag_mode(mut self, drag_mode: &str) -> Layout {
+        self.drag_mode = Some(drag_mode.to_owned());
+        self
+    }
+
+    pub fn select_direction(mut self, select_direction: &str) -> Layout {
+        self.select_direction = Some(select_direction.to_owned());
+        self
+    }
+
+    pub fn hover_distance(mut self, hover_distance: i32) -> Layout {
+        self.hover_distance = Some(hover_distance);
+        self
+    }
+
+    pub fn spike_distance(mut self, spike_distance: i32) -> Layout {
+        self.spike_distance = Some(spike_distance);
+        self
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Layout {
+        self.hover_label = Some(hover_label);
+        self
+    }
+
+    pub fn grid(mut self, grid: LayoutGrid) -> Layout {
+        self.grid = Some(grid);
+        self
+    }
+
+    pub fn calendar(mut self, calendar: Calendar) -> Layout {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    pub fn xaxis(mut self, xaxis: Axis) -> Layout {
+        self.xaxis = Some(xaxis);
+        self
+    }
+
+    pub fn yaxis(mut self, yaxis: Axis) -> Layout {
+        self.yaxis = Some(yaxis);
+        self
+    }
+
+    /// Sets the `n`th x axis, counting from 1, so `x_axis_n(1, axis)` is the same as
+    /// `xaxis(axis)`. Traces are placed on it with `.x_axis("x<n>")`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn x_axis_n(mut self, n: usize, axis: Axis) -> Layout {
+        match n {
+            1 => self.xaxis = Some(axis),
+            _ => {
+                self.axes.insert(indexed_name("xaxis", n), axis);
+            }
+        }
+        self
+    }
+
+    /// Sets the `n`th y axis, counting from 1, so `y_axis_n(1, axis)` is the same as
+    /// `yaxis(axis)`. Traces are placed on it with `.y_axis("y<n>")`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn y_axis_n(mut self, n: usize, axis: Axis) -> Layout {
+        match n {
+            1 => self.yaxis = Some(axis),
+            _ => {
+                self.axes.insert(indexed_name("yaxis", n), axis);
+            }
+        }
+        self
+    }
+
+    pub fn scene(mut self, scene: LayoutScene) -> Layout {
+        self.scene = Some(Box::new(scene));
+        self
+    }
+
+    /// Sets the `n`th 3D scene, counting from 1, so `scene_n(1, scene)` is the same as
+    /// `scene(scene)`. Traces are placed on it with `.scene("scene<n>")`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn scene_n(mut self, n: usize, scene: LayoutScene) -> Layout {
+        match n {
+            1 => self.scene = Some(Box::new(scene)),
+            _ => {
+                self.scenes.insert(indexed_name("scene", n), scene);
+            }
+        }
+        self
+    }
+
+    pub fn polar(mut self, polar: LayoutPolar) -> Layout {
+        self.polar = Some(Box::new(polar));
+        self
+    }
+
+    /// Sets the `n`th polar subplot, counting from 1, so `polar_n(1, polar)` is the same as
+    /// `polar(polar)`. Traces are placed on it with `.subplot("polar<n>")`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn polar_n(mut self, n: usize, polar: LayoutPolar) -> Layout {
+        match n {
+            1 => self.polar = Some(Box::new(polar)),
+            _ => {
+                self.polars.insert(indexed_name("polar", n), polar);
+            }
+        }
+        self
+    }
+
+    pub fn template(mut self, template: Template) -> Layout {
+        self.template = Some(template);
+        self
+    }
+
+    pub fn shapes(mut self, shapes: Vec<Shape>) -> Layout {
+        self.shapes = Some(shapes);
+        self
+    }
+
+    pub fn annotations(mut self, annotations: Vec<Annotation>) -> Layout {
+        self.annotations = Some(annotations);
+        self
+    }
+
+    /// Add an `Annotation` after any already set on the `Layout`.
+    pub fn add_annotation(mut self, annotation: Annotation) -> Layout {
+        self.annotations
+            .get_or_insert_with(Vec::new)
+            .push(annotation);
+        self
+    }
+
+    pub fn update_menus(mut self, update_menus: Vec<UpdateMenu>) -> Layout {
+        self.update_menus = Some(update_menus);
+        self
+    }
+
+    pub fn sliders(mut self, sliders: Vec<Slider>) -> Layout {
+        self.sliders = Some(sliders);
+        self
+    }
+
+    pub fn images(mut self, images: Vec<LayoutImage>) -> Layout {
+        self.images = Some(images);
+        self
+    }
+
+    pub fn box_mode(mut self, box_mode: BoxMode) -> Layout {
+        self.box_mode = Some(box_mode);
+        self
+    }
+
+    pub fn box_gap(mut self, box_gap: f64) -> Layout {
+        self.box_gap = Some(box_gap);
+        self
+    }
+
+    pub fn box_group_gap(mut self, box_group_gap: f64) -> Layout {
+        self.box_group_gap = Some(box_group_gap);
+        self
+    }
+
+    pub fn bar_mode(mut self, bar_mode: BarMode) -> Layout {
+        self.bar_mode = Some(bar_mode);
+        self
+    }
+
+    pub fn bar_norm(mut self, bar_norm: BarNorm) -> Layout {
+        self.bar_norm = Some(bar_norm);
+        self
+    }
+
+    pub fn bar_gap(mut self, bar_gap: f64) -> Layout {
+        self.bar_gap = Some(bar_gap);
+        self
+    }
+
+    pub fn bar_group_gap(mut self, bar_group_gap: f64) -> Layout {
+        self.bar_group_gap = Some(bar_group_gap);
+        self
+    }
+
+    pub fn violin_mode(mut self, violin_mode: ViolinMode) -> Layout {
+        self.violin_mode = Some(violin_mode);
+        self
+    }
+
+    pub fn violin_gap(mut self, violin_gap: f64) -> Layout {
+        self.violin_gap = Some(violin_gap);
+        self
+    }
+
+    pub fn violin_group_gap(mut self, violin_group_gap: f64) -> Layout {
+        self.violin_group_gap = Some(violin_group_gap);
+        self
+    }
+
+    pub fn waterfall_mode(mut self, waterfall_mode: WaterfallMode) -> Layout {
+        self.waterfall_mode = Some(waterfall_mode);
+        self
+    }
+
+    pub fn waterfall_gap(mut self, waterfall_gap: f64) -> Layout {
+        self.waterfall_gap = Some(waterfall_gap);
+        self
+    }
+
+    pub fn waterfall_group_gap(mut self, waterfall_group_gap: f64) -> Layout {
+        self.waterfall_group_gap = Some(waterfall_group_gap);
+        self
+    }
+
+    pub fn pie_colorway<C: Color>(mut self, pie_colorway: Vec<C>) -> Layout {
+        let pie_colorway = private::to_color_array(pie_colorway);
+        self.pie_colorway = Some(pie_colorway);
+        self
+    }
+
+    pub fn extend_pie_colors(mut self, extend_pie_colors: bool) -> Layout {
+        self.extend_pie_colors = Some(extend_pie_colors);
+        self
+    }
+
+    pub fn sunburst_colorway<C: Color>(mut self, sunburst_colorway: Vec<C>) -> Layout {
+        let sunburst_colorway = private::to_color_array(sunburst_colorway);
+        self.sunburst_colorway = Some(sunburst_colorway);
+        self
+    }
+
+    pub fn extend_sunburst_colors(mut self, extend_sunburst_colors: bool) -> Layout {
+        self.extend_sunburst_colors = Some(extend_sunburst_colors);
+        self
+    }
+
+    pub fn treemap_colorway<C: Color>(mut self, treemap_colorway: Vec<C>) -> Layout {
+        let treemap_colorway = private::to_color_array(treemap_colorway);
+        self.treemap_colorway = Some(treemap_colorway);
+        self
+    }
+
+    pub fn extend_treemap_colors(mut self, extend_treemap_colors: bool) -> Layout {
+        self.extend_treemap_colors = Some(extend_treemap_colors);
+        self
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Trace for Layout {
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Shape {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+    layout_type: Option<LayoutType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layer: Option<Layer>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xref")]
+    x_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xsizemode")]
+    x_size_mode: Option<XSizeMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xanchor")]
+    x_anchor: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x0: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x1: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yref")]
+    y_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ysizemode")]
+    y_size_mode: Option<YSizeMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yanchor")]
+    y_anchor: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y0: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y1: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
+    fill_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fillrule")]
+    fill_rule: Option<FillRule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    editable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
+    template_item_name: Option<String>
+}
+
+impl Shape {
+    pub fn new() -> Shape {
+        Shape {
+            visible: None,
+            layout_type: None,
+            layer: None,
+            x_ref: None,
+            x_size_mode: None,
+            x_anchor: None,
+            x0: None,
+            x1: None,
+            y_ref: None,
+            y_size_mode: None,
+            y_anchor: None,
+            y0: None,
+            y1: None,
+            path: None,
+            opacity: None,
+            line: None,
+            fill_color: None,
+            fill_rule: None,
+            editable: None,
+            name: None,
+            template_item_name: None
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> Shape {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn layout_type(mut self, layout_type: LayoutType) -> Shape {
+        self.layout_type = Some(layout_type);
+        self
+    }
+
+    pub fn layer(mut self, layer: Layer) -> Shape {
+        self.layer = Some(layer);
+        self
+    }
+
+    pub fn x_ref(mut self, x_ref: String) -> Shape {
+        self.x_ref = Some(x_ref);
+        self
+    }
+
+    pub fn x_size_mode(mut self, x_size_mode: XSizeMode) -> Shape {
+        self.x_size_mode = Some(x_size_mode);
+        self
+    }
+
+    pub fn x_anchor(mut self, x_anchor: f64) -> Shape {
+        self.x_anchor = Some(x_anchor);
+        self
+    }
+
+    pub fn x0(mut self, x0: f64) -> Shape {
+        self.x0 = Some(x0);
+        self
+    }
+
+    pub fn x1(mut self, x1: f64) -> Shape {
+        self.x1 = Some(x1);
+        self
+    }
+
+    pub fn y_ref(mut self, y_ref: String) -> Shape {
+        self.y_ref = Some(y_ref);
+        self
+    }
+
+    pub fn y_size_mode(mut self, y_size_mode: YSizeMode) -> Shape {
+        self.y_size_mode = Some(y_size_mode);
+        self
+    }
+
+    pub fn y_anchor(mut self, y_anchor: f64) -> Shape {
+        self.y_anchor = Some(y_anchor);
+        self
+    }
+
+    pub fn y0(mut self, y0: f64) -> Shape {
+        self.y0 = Some(y0);
+        self
+    }
+
+    pub fn y1(mut self, y1: f64) -> Shape {
+        self.y1 = Some(y1);
+        self
+    }
+
+    pub fn path(mut self, path: String) -> Shape {
+        self.path = Some(path);
+        self
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Shape {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    pub fn line(mut self, line: Line) -> Shape {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Shape {
+        self.fill_color = Some(fill_color.to_color_string());
+        self
+    }
+
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Shape {
+        self.fill_rule = Some(fill_rule);
+        self
+    }
+
+    pub fn editable(mut self, editable: bool) -> Shape {
+        self.editable = Some(editable);
+        self
+    }
+
+    pub fn name(mut self, name: String) -> Shape {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn template_item_name(mut self, template_item_name: String) -> Shape {
+        self.template_item_name = Some(template_item_name);
+        self
+    }
+}
+
+impl Default for Shape {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum LayoutType {
+    #[serde(rename="circle")]
+    Circle,
+    #[serde(rename="rect")]
+    Rect,
+    #[serde(rename="path")]
+    Path,
+    #[serde(rename="line")]
+    Line
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Layer {
+    #[serde(rename="above")]
+    Above,
+    #[serde(rename="below")]
+    Below
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum XSizeMode {
+    #[serde(rename="scaled")]
+    Scaled,
+    #[serde(rename="pixel")]
+    Pixel
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum YSizeMode {
+    #[serde(rename="scaled")]
+    Scaled,
+    #[serde(rename="pixel")]
+    Pixel
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Line {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dash: Option<String>
+}
+
+impl Line {
+    pub fn new() -> Line {
+        Line {
+            color: None,
+            width: None,
+            dash: None
+        }
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> Line {
+        self.color = Some(color.to_color_string());
+        self
+    }
+
+    pub fn width(mut self, width: u8) -> Line {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn dash(mut self, dash: String) -> Line {
+        self.dash = Some(dash);
+        self
+    }
+}
+
+impl Default for Line {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum FillRule {
+    #[serde(rename="evenodd")]
+    EvenOdd,
+    #[serde(rename="nonzero")]
+    Nonzero
+}
+
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum HAlign {
+    #[serde(rename = "left")]
+    Left,
+    #[serde(rename = "center")]
+    Center,
+    #[serde(rename = "right")]
+    Right,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ArrowSide {
+    #[serde(rename = "end")]
+    End,
+    #[serde(rename = "start")]
+    Start,
+    #[serde(rename = "end+start")]
+    StartEnd,
+    #[serde(rename = "none")]
+    None,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ClickToShow {
+    #[serde(rename = "false")]
+    False,
+    #[serde(rename = "onoff")]
+    OnOff,
+    #[serde(rename = "onout")]
+    OnOut,
+}
+
+/// A text label placed on the plot, optionally with an arrow pointing at a position.
+///
+/// `x` and `y` are in the coordinates given by `x_ref` and `y_ref`: either `AxisReference::Paper`,
+/// where 0 and 1 are the edges of the plotting area, or an axis such as `AxisReference::axis("y2")`,
+/// in which case they are data values and may be numbers, dates or categories. The arrow tail is
+/// offset by `ax` and `ay` pixels from the head, unless `ax_ref` and `ay_ref` are set to an axis.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Annotation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textangle")]
+    text_angle: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    align: Option<HAlign>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    valign: Option<Align>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
+    background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bordercolor")]
+    border_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "borderpad")]
+    border_pad: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "borderwidth")]
+    border_width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showarrow")]
+    show_arrow: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "arrowcolor")]
+    arrow_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "arrowhead")]
+    arrow_head: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "startarrowhead")]
+    start_arrow_head: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "arrowside")]
+    arrow_side: Option<ArrowSide>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "arrowsize")]
+    arrow_size: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "startarrowsize")]
+    start_arrow_size: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "arrowwidth")]
+    arrow_width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "standoff")]
+    stand_off: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "startstandoff")]
+    start_stand_off: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ax: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ay: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "axref")]
+    ax_ref: Option<AxisReference>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ayref")]
+    ay_ref: Option<AxisReference>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xref")]
+    x_ref: Option<AxisReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xanchor")]
+    x_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xshift")]
+    x_shift: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yref")]
+    y_ref: Option<AxisReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yanchor")]
+    y_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yshift")]
+    y_shift: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "clicktoshow")]
+    click_to_show: Option<private::TruthyEnum<ClickToShow>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xclick")]
+    x_click: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yclick")]
+    y_click: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "captureevents")]
+    capture_events: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
+    template_item_name: Option<String>,
+}
+
+impl Annotation {
+    pub fn new() -> Annotation {
+        Annotation {
+            visible: None,
+            text: None,
+            text_angle: None,
+            font: None,
+            width: None,
+            height: None,
+            opacity: None,
+            align: None,
+            valign: None,
+            background_color: None,
+            border_color: None,
+            border_pad: None,
+            border_width: None,
+            show_arrow: None,
+            arrow_color: None,
+            arrow_head: None,
+            start_arrow_head: None,
+            arrow_side: None,
+            arrow_size: None,
+            start_arrow_size: None,
+            arrow_width: None,
+            stand_off: None,
+            start_stand_off: None,
+            ax: None,
+            ay: None,
+            ax_ref: None,
+            ay_ref: None,
+            x_ref: None,
+            x: None,
+            x_anchor: None,
+            x_shift: None,
+            y_ref: None,
+            y: None,
+            y_anchor: None,
+            y_shift: None,
+            click_to_show: None,
+            x_click: None,
+            y_click: None,
+            hover_text: None,
+            hover_label: None,
+            capture_events: None,
+            name: None,
+            template_item_name: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> Annotation {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn text(mut self, text: &str) -> Annotation {
+        self.text = Some(text.to_owned());
+        self
+    }
+
+    pub fn text_angle(mut self, text_angle: f64) -> Annotation {
+        self.text_angle = Some(text_angle);
+        self
+    }
+
+    pub fn font(mut self, font: Font) -> Annotation {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn width(mut self, width: f64) -> Annotation {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: f64) -> Annotation {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Annotation {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    pub fn align(mut self, align: HAlign) -> Annotation {
+        self.align = Some(align);
+        self
+    }
+
+    pub fn valign(mut self, valign: Align) -> Annotation {
+        self.valign = Some(valign);
+        self
+    }
+
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Annotation {
+        self.background_color = Some(background_color.to_color_string());
+        self
+    }
+
+    pub fn border_color<C: Color>(mut self, border_color: C) -> Annotation {
+        self.border_color = Some(border_color.to_color_string());
+        self
+    }
+
+    pub fn border_pad(mut self, border_pad: f64) -> Annotation {
+        self.border_pad = Some(border_pad);
+        self
+    }
+
+    pub fn border_width(mut self, border_width: f64) -> Annotation {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    pub fn show_arrow(mut self, show_arrow: bool) -> Annotation {
+        self.show_arrow = Some(show_arrow);
+        self
+    }
+
+    pub fn arrow_color<C: Color>(mut self, arrow_color: C) -> Annotation {
+        self.arrow_color = Some(arrow_color.to_color_string());
+        self
+    }
+
+    pub fn arrow_head(mut self, arrow_head: u8) -> Annotation {
+        self.arrow_head = Some(arrow_head);
+        self
+    }
+
+    pub fn start_arrow_head(mut self, start_arrow_head: u8) -> Annotation {
+        self.start_arrow_head = Some(start_arrow_head);
+        self
+    }
+
+    pub fn arrow_side(mut self, arrow_side: ArrowSide) -> Annotation {
+        self.arrow_side = Some(arrow_side);
+        self
+    }
+
+    pub fn arrow_size(mut self, arrow_size: f64) -> Annotation {
+        self.arrow_size = Some(arrow_size);
+        self
+    }
+
+    pub fn start_arrow_size(mut self, start_arrow_size: f64) -> Annotation {
+        self.start_arrow_size = Some(start_arrow_size);
+        self
+    }
+
+    pub fn arrow_width(mut self, arrow_width: f64) -> Annotation {
+        self.arrow_width = Some(arrow_width);
+        self
+    }
+
+    pub fn stand_off(mut self, stand_off: f64) -> Annotation {
+        self.stand_off = Some(stand_off);
+        self
+    }
+
+    pub fn start_stand_off(mut self, start_stand_off: f64) -> Annotation {
+        self.start_stand_off = Some(start_stand_off);
+        self
+    }
+
+    pub fn ax<V: Serialize>(mut self, ax: V) -> Annotation {
+        self.ax = Some(serde_json::to_value(ax).unwrap());
+        self
+    }
+
+    pub fn ay<V: Serialize>(mut self, ay: V) -> Annotation {
+        self.ay = Some(serde_json::to_value(ay).unwrap());
+        self
+    }
+
+    pub fn ax_ref(mut self, ax_ref: AxisReference) -> Annotation {
+        self.ax_ref = Some(ax_ref);
+        self
+    }
+
+    pub fn ay_ref(mut self, ay_ref: AxisReference) -> Annotation {
+        self.ay_ref = Some(ay_ref);
+        self
+    }
+
+    pub fn x_ref(mut self, x_ref: AxisReference) -> Annotation {
+        self.x_ref = Some(x_ref);
+        self
+    }
+
+    pub fn x<V: Serialize>(mut self, x: V) -> Annotation {
+        self.x = Some(serde_json::to_value(x).unwrap());
+        self
+    }
+
+    pub fn x_anchor(mut self, x_anchor: Anchor) -> Annotation {
+        self.x_anchor = Some(x_anchor);
+        self
+    }
+
+    pub fn x_shift(mut self, x_shift: f64) -> Annotation {
+        self.x_shift = Some(x_shift);
+        self
+    }
+
+    pub fn y_ref(mut self, y_ref: AxisReference) -> Annotation {
+        self.y_ref = Some(y_ref);
+        self
+    }
+
+    pub fn y<V: Serialize>(mut self, y: V) -> Annotation {
+        self.y = Some(serde_json::to_value(y).unwrap());
+        self
+    }
+
+    pub fn y_anchor(mut self, y_anchor: Anchor) -> Annotation {
+        self.y_anchor = Some(y_anchor);
+        self
+    }
+
+    pub fn y_shift(mut self, y_shift: f64) -> Annotation {
+        self.y_shift = Some(y_shift);
+        self
+    }
+
+    pub fn click_to_show(mut self, click_to_show: ClickToShow) -> Annotation {
+        self.click_to_show = Some(private::TruthyEnum { e: click_to_show });
+        self
+    }
+
+    pub fn x_click<V: Serialize>(mut self, x_click: V) -> Annotation {
+        self.x_click = Some(serde_json::to_value(x_click).unwrap());
+        self
+    }
+
+    pub fn y_click<V: Serialize>(mut self, y_click: V) -> Annotation {
+        self.y_click = Some(serde_json::to_value(y_click).unwrap());
+        self
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Annotation {
+        self.hover_text = Some(hover_text.to_owned());
+        self
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Annotation {
+        self.hover_label = Some(hover_label);
+        self
+    }
+
+    pub fn capture_events(mut self, capture_events: bool) -> Annotation {
+        self.capture_events = Some(capture_events);
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Annotation {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    pub fn template_item_name(mut self, template_item_name: &str) -> Annotation {
+        self.template_item_name = Some(template_item_name.to_owned());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum UpdateMenuType {
+    #[serde(rename = "dropdown")]
+    Dropdown,
+    #[serde(rename = "buttons")]
+    Buttons,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum UpdateMenuDirection {
+    #[serde(rename = "left")]
+    Left,
+    #[serde(rename = "right")]
+    Right,
+    #[serde(rename = "up")]
+    Up,
+    #[serde(rename = "down")]
+    Down,
+}
+
+/// A dropdown or a row of buttons that change the plot when clicked, set with
+/// `Layout::update_menus`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateMenu {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
+    menu_type: Option<UpdateMenuType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<UpdateMenuDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showactive")]
+    show_active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    buttons: Option<Vec<Button>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xanchor")]
+    x_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yanchor")]
+    y_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pad: Option<Pad>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
+    background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bordercolor")]
+    border_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "borderwidth")]
+    border_width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
+    template_item_name: Option<String>,
+}
+
+impl UpdateMenu {
+    pub fn new() -> UpdateMenu {
+        UpdateMenu {
+            visible: None,
+            menu_type: None,
+            direction: None,
+            active: None,
+            show_active: None,
+            buttons: None,
+            x: None,
+            x_anchor: None,
+            y: None,
+            y_anchor: None,
+            pad: None,
+            font: None,
+            background_color: None,
+            border_color: None,
+            border_width: None,
+            name: None,
+            template_item_name: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> UpdateMenu {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn menu_type(mut self, menu_type: UpdateMenuType) -> UpdateMenu {
+        self.menu_type = Some(menu_type);
+        self
+    }
+
+    pub fn direction(mut self, direction: UpdateMenuDirection) -> UpdateMenu {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn active(mut self, active: i32) -> UpdateMenu {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn show_active(mut self, show_active: bool) -> UpdateMenu {
+        self.show_active = Some(show_active);
+        self
+    }
+
+    pub fn buttons(mut self, buttons: Vec<Button>) -> UpdateMenu {
+        self.buttons = Some(buttons);
+        self
+    }
+
+    pub fn x(mut self, x: f64) -> UpdateMenu {
+        self.x = Some(x);
+        self
+    }
+
+    pub fn x_anchor(mut self, x_anchor: Anchor) -> UpdateMenu {
+        self.x_anchor = Some(x_anchor);
+        self
+    }
+
+    pub fn y(mut self, y: f64) -> UpdateMenu {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn y_anchor(mut self, y_anchor: Anchor) -> UpdateMenu {
+        self.y_anchor = Some(y_anchor);
+        self
+    }
+
+    pub fn pad(mut self, pad: Pad) -> UpdateMenu {
+        self.pad = Some(pad);
+        self
+    }
+
+    pub fn font(mut self, font: Font) -> UpdateMenu {
+        self.font = Some(font);
+        self
+    }
+
+    pub fn background_color<C: Color>(mut self, background_color: C) -> UpdateMenu {
+        self.background_color = Some(background_color.to_color_string());
+        self
+    }
+
+    pub fn border_color<C: Color>(mut self, border_color: C) -> UpdateMenu {
+        self.border_color = Some(border_color.to_color_string());
+        self
+    }
+
+    pub fn border_width(mut self, border_width: f64) -> UpdateMenu {
+        self.border_width = Some(border_width);
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> UpdateMenu {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    pub fn template_item_name(mut self, template_item_name: &str) -> UpdateMenu {
+        self.template_item_name = Some(template_item_name.to_owned());
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ButtonMethod {
+    #[serde(rename = "restyle")]
+    Restyle,
+    #[serde(rename = "relayout")]
+    Relayout,
+    #[serde(rename = "update")]
+    Update,
+    #[serde(rename = "animate")]
+    Animate,
+    #[serde(rename = "skip")]
+    Skip,
+}
+
+/// A button of an `UpdateMenu`. What it does is set with one of `restyle`, `relayout`, `update`
+/// or `animate`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Button {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    method: Option<ButtonMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execute: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
+    template_item_name: Option<String>,
+}
+
+impl Button {
+    pub fn new() -> Button {
+        Button {
+            visible: None,
+            label: None,
+            method: None,
+            args: None,
+            execute: None,
+            name: None,
+            template_item_name: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> Button {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> Button {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    pub fn execute(mut self, execute: bool) -> Button {
+        self.execute = Some(execute);
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Button {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    pub fn template_item_name(mut self, template_item_name: &str) -> Button {
+        self.template_item_name = Some(template_item_name.to_owned());
+        self
+    }
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    template: Option<String>,
+    /// Change trace attributes with `Plotly.restyle`.
+    pub fn restyle(mut self, restyle: Restyle) -> Button {
+        self.method = Some(ButtonMethod::Restyle);
+        self.args = Some(restyle.into_args(None));
+        self
+    }
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    grid: Option<LayoutGrid>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    calendar: Option<Calendar>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    xaxis: Option<Axis>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    yaxis: Option<Axis>,
+    /// Change layout attributes with `Plotly.relayout`.
+    pub fn relayout(mut self, relayout: Relayout) -> Button {
+        self.method = Some(ButtonMethod::Relayout);
+        self.args = Some(vec![relayout.values]);
+        self
+    }
 
-    // ternary: Option<LayoutTernary>,
-    // scene: Option<LayoutScene>,
+    /// Change both trace and layout attributes with `Plotly.update`.
+    pub fn update(mut self, restyle: Restyle, relayout: Relayout) -> Button {
+        self.method = Some(ButtonMethod::Update);
+        self.args = Some(restyle.into_args(Some(relayout)));
+        self
+    }
 
-    // polar: Option<LayoutPolar>,
-    // annotations: Option<LayoutAnnotations>,
+    /// Play animation frames with `Plotly.animate`.
+    pub fn animate(mut self, animate: Animate) -> Button {
+        self.method = Some(ButtonMethod::Animate);
+        self.args = Some(vec![animate.frames, animate.options]);
+        self
+    }
+}
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    shapes: Option<Vec<Shape>>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "boxmode")]
-    box_mode: Option<BoxMode>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "boxgap")]
-    box_gap: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "boxgroupgap")]
-    box_group_gap: Option<f64>,
+/// Trace attributes changed by a `Button` or `SliderStep`, as passed to `Plotly.restyle`.
+///
+/// Attributes are given by their plotly.js name, using dots for nested attributes, e.g.
+/// `"marker.color"`.
+#[derive(Debug)]
+pub struct Restyle {
+    values: serde_json::Value,
+    traces: Option<Vec<usize>>,
+}
 
-    #[serde(skip_serializing_if = "Option::is_none", rename = "barmode")]
-    bar_mode: Option<BarMode>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "barnorm")]
-    bar_norm: Option<BarNorm>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "bargap")]
-    bar_gap: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "bargroupgap")]
-    bar_group_gap: Option<f64>,
+impl Restyle {
+    pub fn new() -> Restyle {
+        Restyle {
+            values: serde_json::json!({}),
+            traces: None,
+        }
+    }
 
-    #[serde(skip_serializing_if = "Option::is_none", rename = "violinmode")]
-    violin_mode: Option<ViolinMode>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "violingap")]
-    violin_gap: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "violingroupgap")]
-    violin_group_gap: Option<f64>,
+    /// Set `attribute` to `value` on all the restyled traces.
+    pub fn set<V: Serialize>(mut self, attribute: &str, value: V) -> Restyle {
+        // Plotly.restyle applies array values element by element to the traces, so the value is
+        // wrapped to set it as a whole on every trace.
+        self.values[attribute] = serde_json::json!([value]);
+        self
+    }
 
-    #[serde(skip_serializing_if = "Option::is_none", rename = "waterfallmode")]
-    waterfall_mode: Option<WaterfallMode>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "waterfallgap")]
-    waterfall_gap: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "waterfallgroupgap")]
-    waterfall_group_gap: Option<f64>,
+    /// Set `attribute` on each of the restyled traces to the value at the same position in
+    /// `values`.
+    pub fn set_per_trace<V: Serialize>(mut self, attribute: &str, values: Vec<V>) -> Restyle {
+        self.values[attribute] = serde_json::json!(values);
+        self
+    }
 
-    #[serde(skip_serializing_if = "Option::is_none", rename = "piecolorway")]
-    pie_colorway: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "extendpiecolors")]
-    extend_pie_colors: Option<bool>,
+    /// Only restyle the traces at these indices, in the order they were added to the `Plot`.
+    /// Defaults to all traces.
+    pub fn traces(mut self, traces: Vec<usize>) -> Restyle {
+        self.traces = Some(traces);
+        self
+    }
 
-    #[serde(skip_serializing_if = "Option::is_none", rename = "sunburstcolorway")]
-    sunburst_colorway: Option<Vec<String>>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "extendsuburstcolors")]
-    extend_sunburst_colors: Option<bool>,
+    fn into_args(self, relayout: Option<Relayout>) -> Vec<serde_json::Value> {
+        let mut args = vec![self.values];
+        args.extend(relayout.map(|relayout| relayout.values));
+        args.extend(self.traces.map(|traces| serde_json::json!(traces)));
+        args
+    }
 }
 
-impl Layout {
-    pub fn new() -> Layout {
-        Layout {
-            title: None,
-            show_legend: None,
-            legend: None,
-            margin: None,
-            auto_size: None,
-            width: None,
-            height: None,
-            font: None,
-            separators: None,
-            paper_background_color: None,
-            plot_background_color: None,
-            color_scale: None,
-            colorway: None,
-            color_axis: None,
-            hover_mode: None,
-            click_mode: None,
-            drag_mode: None,
-            select_direction: None,
-            hover_distance: None,
-            spike_distance: None,
-            hover_label: None,
-            grid: None,
-            calendar: None,
-            xaxis: None,
-            yaxis: None,
-            template: None,
-            shapes: None,
-            box_mode: None,
-            box_gap: None,
-            box_group_gap: None,
-            bar_mode: None,
-            bar_norm: None,
-            bar_gap: None,
-            bar_group_gap: None,
+impl Default for Restyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-            violin_mode: None,
-            violin_gap: None,
-            violin_group_gap: None,
+/// Layout attributes changed by a `Button` or `SliderStep`, as passed to `Plotly.relayout`.
+///
+/// Attributes are given by their plotly.js name, using dots for nested attributes, e.g.
+/// `"xaxis.range"`.
+#[derive(Debug)]
+pub struct Relayout {
+    values: serde_json::Value,
+}
 
-            waterfall_mode: None,
-            waterfall_gap: None,
-            waterfall_group_gap: None,
+impl Relayout {
+    pub fn new() -> Relayout {
+        Relayout {
+            values: serde_json::json!({}),
+        }
+    }
 
-            pie_colorway: None,
-            extend_pie_colors: None,
+    pub fn set<V: Serialize>(mut self, attribute: &str, value: V) -> Relayout {
+        self.values[attribute] = serde_json::json!(value);
+        self
+    }
+}
 
-            sunburst_colorway: None,
-            extend_sunburst_colors: None,
+impl Default for Relayout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The frames played by a `Button` or `SliderStep`, as passed to `Plotly.animate`.
+#[derive(Debug)]
+pub struct Animate {
+    frames: serde_json::Value,
+    options: serde_json::Value,
+}
+
+impl Animate {
+    /// Play all frames.
+    pub fn new() -> Animate {
+        Animate {
+            frames: serde_json::Value::Null,
+            options: serde_json::json!({}),
         }
     }
 
-    pub fn title(mut self, title: Title) -> Layout {
-        self.title = Some(title);
-        self
+    /// Stop a running animation, e.g. for a pause button.
+    pub fn pause() -> Animate {
+        let options = AnimationOptions::new()
+            .mode(AnimationMode::Immediate)
+            .frame(FrameSettings::new().duration(0.0).redraw(false))
+            .transition(Transition::new().duration(0.0));
+        Animate {
+            frames: serde_json::json!([null]),
+            options: serde_json::to_value(options).unwrap(),
+        }
     }
 
-    pub fn show_legend(mut self, show_legend: bool) -> Layout {
-        self.show_legend = Some(show_legend);
+    /// Play the frames with these names or group names, in order.
+    pub fn frames<S: AsRef<str>>(mut self, frames: Vec<S>) -> Animate {
+        self.frames = serde_json::json!(private::owned_string_vector(frames));
         self
     }
 
-    pub fn legend(mut self, legend: Legend) -> Layout {
-        self.legend = Some(legend);
+    pub fn options(mut self, options: AnimationOptions) -> Animate {
+        self.options = serde_json::to_value(options).unwrap();
         self
     }
+}
 
-    pub fn margin(mut self, margin: Margin) -> Layout {
-        self.margin = Some(margin);
-        self
+impl Default for Animate {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    pub fn auto_size(mut self, auto_size: bool) -> Layout {
-        self.auto_size = Some(auto_size);
-        self
+/// The current value label of a `Slider`, showing the label of the active step.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct CurrentValue {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xanchor")]
+    x_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font: Option<Font>,
+}
+
+impl CurrentValue {
+    pub fn new() -> CurrentValue {
+        CurrentValue {
+            visible: None,
+            x_anchor: None,
+            offset: None,
+            prefix: None,
+            suffix: None,
+            font: None,
+        }
     }
 
-    pub fn width(mut self, width: usize) -> Layout {
-        self.width = Some(width);
+    pub fn visible(mut self, visible: bool) -> CurrentValue {
+        self.visible = Some(visible);
         self
     }
 
-    pub fn height(mut self, height: usize) -> Layout {
-        self.height = Some(height);
+    pub fn x_anchor(mut self, x_anchor: Anchor) -> CurrentValue {
+        self.x_anchor = Some(x_anchor);
         self
     }
 
-    pub fn font(mut self, font: Font) -> Layout {
-        self.font = Some(font);
+    pub fn offset(mut self, offset: f64) -> CurrentValue {
+        self.offset = Some(offset);
         self
     }
 
-    pub fn separators(mut self, separators: &str) -> Layout {
-        self.separators = Some(separators.to_owned());
+    pub fn prefix(mut self, prefix: &str) -> CurrentValue {
+        self.prefix = Some(prefix.to_owned());
         self
     }
 
-    pub fn paper_background_color<C: Color>(mut self, paper_background_color: C) -> Layout {
-        self.paper_background_color = Some(paper_background_color.to_color_string());
+    pub fn suffix(mut self, suffix: &str) -> CurrentValue {
+        self.suffix = Some(suffix.to_owned());
         self
     }
 
-    pub fn plot_background_color<C: Color>(mut self, plot_background_color: C) -> Layout {
-        self.plot_background_color = Some(plot_background_color.to_color_string());
+    pub fn font(mut self, font: Font) -> CurrentValue {
+        self.font = Some(font);
         self
     }
+}
 
-    pub fn color_scale(mut self, color_scale: LayoutColorScale) -> Layout {
-        self.color_scale = Some(color_scale);
+/// A step of a `Slider`. What selecting it does is set with one of `restyle`, `relayout`, `update`
+/// or `animate`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct SliderStep {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    method: Option<ButtonMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execute: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
+    template_item_name: Option<String>,
+}
+
+impl SliderStep {
+    pub fn new() -> SliderStep {
+        SliderStep {
+            visible: None,
+            label: None,
+            value: None,
+            method: None,
+            args: None,
+            execute: None,
+            name: None,
+            template_item_name: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> SliderStep {
+        self.visible = Some(visible);
         self
     }
 
-    pub fn colorway<C: Color>(mut self, colorway: Vec<C>) -> Layout {
-        let colorway = private::to_color_array(colorway);
-        self.colorway = Some(colorway);
+    pub fn label(mut self, label: &str) -> SliderStep {
+        self.label = Some(label.to_owned());
         self
     }
 
-    pub fn color_axis(mut self, color_axis: ColorAxis) -> Layout {
-        self.color_axis = Some(color_axis);
+    pub fn value(mut self, value: &str) -> SliderStep {
+        self.value = Some(value.to_owned());
         self
     }
 
-    pub fn hover_mode(mut self, hover_mode: &str) -> Layout {
-        self.hover_mode = Some(hover_mode.to_owned());
+    pub fn execute(mut self, execute: bool) -> SliderStep {
+        self.execute = Some(execute);
         self
     }
 
-    pub fn click_mode(mut self, click_mode: &str) -> Layout {
-        self.click_mode = Some(click_mode.to_owned());
+    pub fn name(mut self, name: &str) -> SliderStep {
+        self.name = Some(name.to_owned());
         self
     }
 
-    pub fn drag_mode(mut self, drag_mode: &str) -> Layout {
-        self.drag_mode = Some(drag_mode.to_owned());
+    pub fn template_item_name(mut self, template_item_name: &str) -> SliderStep {
+        self.template_item_name = Some(template_item_name.to_owned());
         self
     }
 
-    pub fn select_direction(mut self, select_direction: &str) -> Layout {
-        self.select_direction = Some(select_direction.to_owned());
+    pub fn restyle(mut self, restyle: Restyle) -> SliderStep {
+        self.method = Some(ButtonMethod::Restyle);
+        self.args = Some(restyle.into_args(None));
         self
     }
 
-    pub fn hover_distance(mut self, hover_distance: i32) -> Layout {
-        self.hover_distance = Some(hover_distance);
+    pub fn relayout(mut self, relayout: Relayout) -> SliderStep {
+        self.method = Some(ButtonMethod::Relayout);
+        self.args = Some(vec![relayout.values]);
         self
     }
 
-    pub fn spike_distance(mut self, spike_distance: i32) -> Layout {
-        self.spike_distance = Some(spike_distance);
+    pub fn update(mut self, restyle: Restyle, relayout: Relayout) -> SliderStep {
+        self.method = Some(ButtonMethod::Update);
+        self.args = Some(restyle.into_args(Some(relayout)));
         self
     }
 
-    pub fn hover_label(mut self, hover_label: Label) -> Layout {
-        self.hover_label = Some(hover_label);
+    pub fn animate(mut self, animate: Animate) -> SliderStep {
+        self.method = Some(ButtonMethod::Animate);
+        self.args = Some(vec![animate.frames, animate.options]);
         self
     }
+}
+
+/// A slider selecting one of its steps, set with `Layout::sliders`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Slider {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    steps: Option<Vec<SliderStep>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "lenmode")]
+    len_mode: Option<ThicknessMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    len: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xanchor")]
+    x_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yanchor")]
+    y_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pad: Option<Pad>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transition: Option<Transition>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "currentvalue")]
+    current_value: Option<CurrentValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "activebgcolor")]
+    active_background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bgcolor")]
+    background_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "bordercolor")]
+    border_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "borderwidth")]
+    border_width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ticklen")]
+    tick_len: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickcolor")]
+    tick_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "tickwidth")]
+    tick_width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "minorticklen")]
+    minor_tick_len: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
+    template_item_name: Option<String>,
+}
+
+impl Slider {
+    pub fn new() -> Slider {
+        Slider {
+            visible: None,
+            active: None,
+            steps: None,
+            len_mode: None,
+            len: None,
+            x: None,
+            x_anchor: None,
+            y: None,
+            y_anchor: None,
+            pad: None,
+            transition: None,
+            current_value: None,
+            font: None,
+            active_background_color: None,
+            background_color: None,
+            border_color: None,
+            border_width: None,
+            tick_len: None,
+            tick_color: None,
+            tick_width: None,
+            minor_tick_len: None,
+            name: None,
+            template_item_name: None,
+        }
+    }
 
-    pub fn grid(mut self, grid: LayoutGrid) -> Layout {
-        self.grid = Some(grid);
+    pub fn visible(mut self, visible: bool) -> Slider {
+        self.visible = Some(visible);
         self
     }
 
-    pub fn calendar(mut self, calendar: Calendar) -> Layout {
-        self.calendar = Some(calendar);
+    pub fn active(mut self, active: usize) -> Slider {
+        self.active = Some(active);
         self
     }
 
-    pub fn xaxis(mut self, xaxis: Axis) -> Layout {
-        self.xaxis = Some(xaxis);
+    pub fn steps(mut self, steps: Vec<SliderStep>) -> Slider {
+        self.steps = Some(steps);
         self
     }
 
-    pub fn yaxis(mut self, yaxis: Axis) -> Layout {
-        self.yaxis = Some(yaxis);
+    pub fn len_mode(mut self, len_mode: ThicknessMode) -> Slider {
+        self.len_mode = Some(len_mode);
         self
     }
 
-    pub fn template(mut self, template: &str) -> Layout {
-        self.template = Some(template.to_owned());
+    pub fn len(mut self, len: f64) -> Slider {
+        self.len = Some(len);
         self
     }
 
-    pub fn shapes(mut self, shapes: Vec<Shape>) -> Layout {
-        self.shapes = Some(shapes);
+    pub fn x(mut self, x: f64) -> Slider {
+        self.x = Some(x);
         self
     }
 
-    pub fn box_mode(mut self, box_mode: BoxMode) -> Layout {
-        self.box_mode = Some(box_mode);
+    pub fn x_anchor(mut self, x_anchor: Anchor) -> Slider {
+        self.x_anchor = Some(x_anchor);
         self
     }
 
-    pub fn box_gap(mut self, box_gap: f64) -> Layout {
-        self.box_gap = Some(box_gap);
+    pub fn y(mut self, y: f64) -> Slider {
+        self.y = Some(y);
         self
     }
 
-    pub fn box_group_gap(mut self, box_group_gap: f64) -> Layout {
-        self.box_group_gap = Some(box_group_gap);
+    pub fn y_anchor(mut self, y_anchor: Anchor) -> Slider {
+        self.y_anchor = Some(y_anchor);
         self
     }
 
-    pub fn bar_mode(mut self, bar_mode: BarMode) -> Layout {
-        self.bar_mode = Some(bar_mode);
+    pub fn pad(mut self, pad: Pad) -> Slider {
+        self.pad = Some(pad);
         self
     }
 
-    pub fn bar_norm(mut self, bar_norm: BarNorm) -> Layout {
-        self.bar_norm = Some(bar_norm);
+    pub fn transition(mut self, transition: Transition) -> Slider {
+        self.transition = Some(transition);
         self
     }
 
-    pub fn bar_gap(mut self, bar_gap: f64) -> Layout {
-        self.bar_gap = Some(bar_gap);
+    pub fn current_value(mut self, current_value: CurrentValue) -> Slider {
+        self.current_value = Some(current_value);
         self
     }
 
-    pub fn bar_group_gap(mut self, bar_group_gap: f64) -> Layout {
-        self.bar_group_gap = Some(bar_group_gap);
+    pub fn font(mut self, font: Font) -> Slider {
+        self.font = Some(font);
         self
     }
 
-    pub fn violin_mode(mut self, violin_mode: ViolinMode) -> Layout {
-        self.violin_mode = Some(violin_mode);
+    pub fn active_background_color<C: Color>(mut self, active_background_color: C) -> Slider {
+        self.active_background_color = Some(active_background_color.to_color_string());
         self
     }
 
-    pub fn violin_gap(mut self, violin_gap: f64) -> Layout {
-        self.violin_gap = Some(violin_gap);
+    pub fn background_color<C: Color>(mut self, background_color: C) -> Slider {
+        self.background_color = Some(background_color.to_color_string());
         self
     }
 
-    pub fn violin_group_gap(mut self, violin_group_gap: f64) -> Layout {
-        self.violin_group_gap = Some(violin_group_gap);
+    pub fn border_color<C: Color>(mut self, border_color: C) -> Slider {
+        self.border_color = Some(border_color.to_color_string());
         self
     }
 
-    pub fn waterfall_mode(mut self, waterfall_mode: WaterfallMode) -> Layout {
-        self.waterfall_mode = Some(waterfall_mode);
+    pub fn border_width(mut self, border_width: f64) -> Slider {
+        self.border_width = Some(border_width);
         self
     }
 
-    pub fn waterfall_gap(mut self, waterfall_gap: f64) -> Layout {
-        self.waterfall_gap = Some(waterfall_gap);
+    pub fn tick_len(mut self, tick_len: f64) -> Slider {
+        self.tick_len = Some(tick_len);
         self
     }
 
-    pub fn waterfall_group_gap(mut self, waterfall_group_gap: f64) -> Layout {
-        self.waterfall_group_gap = Some(waterfall_group_gap);
+    pub fn tick_color<C: Color>(mut self, tick_color: C) -> Slider {
+        self.tick_color = Some(tick_color.to_color_string());
         self
     }
 
-    pub fn pie_colorway<C: Color>(mut self, pie_colorway: Vec<C>) -> Layout {
-        let pie_colorway = private::to_color_array(pie_colorway);
-        self.pie_colorway = Some(pie_colorway);
+    pub fn tick_width(mut self, tick_width: f64) -> Slider {
+        self.tick_width = Some(tick_width);
         self
     }
 
-    pub fn extend_pie_colors(mut self, extend_pie_colors: bool) -> Layout {
-        self.extend_pie_colors = Some(extend_pie_colors);
+    pub fn minor_tick_len(mut self, minor_tick_len: f64) -> Slider {
+        self.minor_tick_len = Some(minor_tick_len);
         self
     }
 
-    pub fn sunburst_colorway<C: Color>(mut self, sunburst_colorway: Vec<C>) -> Layout {
-        let sunburst_colorway = private::to_color_array(sunburst_colorway);
-        self.sunburst_colorway = Some(sunburst_colorway);
+    pub fn name(mut self, name: &str) -> Slider {
+        self.name = Some(name.to_owned());
         self
     }
 
-    pub fn extend_sunburst_colors(mut self, extend_sunburst_colors: bool) -> Layout {
-        self.extend_sunburst_colors = Some(extend_sunburst_colors);
+    pub fn template_item_name(mut self, template_item_name: &str) -> Slider {
+        self.template_item_name = Some(template_item_name.to_owned());
         self
     }
 }
 
-impl Trace for Layout {
-    fn serialize(&self) -> String {
-        serde_json::to_string(&self).unwrap()
-    }
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ImageSizing {
+    #[serde(rename = "fill")]
+    Fill,
+    #[serde(rename = "contain")]
+    Contain,
+    #[serde(rename = "stretch")]
+    Stretch,
 }
 
-#[derive(Serialize, Debug)]
-pub struct Shape {
+/// An image placed on the plot, such as a logo or a background picture, set with
+/// `Layout::images`.
+///
+/// As with `Annotation`, `x`, `y`, `size_x` and `size_y` are in the coordinates given by `x_ref`
+/// and `y_ref`, either `AxisReference::Paper` or an axis such as `AxisReference::axis("x")`.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct LayoutImage {
     #[serde(skip_serializing_if = "Option::is_none")]
     visible: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
-    layout_type: Option<LayoutType>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    layer: Option<Layer>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xref")]
-    x_ref: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xsizemode")]
-    x_size_mode: Option<XSizeMode>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "xanchor")]
-    x_anchor: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    x0: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    x1: Option<f64>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "yref")]
-    y_ref: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "ysizemode")]
-    y_size_mode: Option<YSizeMode>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "yanchor")]
-    y_anchor: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    y0: Option<f64>,
+    source: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    y1: Option<f64>,
+    layer: Option<Layer>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sizex")]
+    size_x: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "sizey")]
+    size_y: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    path: Option<String>,
+    sizing: Option<ImageSizing>,
     #[serde(skip_serializing_if = "Option::is_none")]
     opacity: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    line: Option<Line>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
-    fill_color: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none", rename = "fillrule")]
-    fill_rule: Option<FillRule>,
+    x: Option<serde_json::Value>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    editable: Option<bool>,
+    y: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xanchor")]
+    x_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yanchor")]
+    y_anchor: Option<Anchor>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xref")]
+    x_ref: Option<AxisReference>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yref")]
+    y_ref: Option<AxisReference>,
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "templateitemname")]
-    template_item_name: Option<String>
+    template_item_name: Option<String>,
 }
 
-impl Shape {
-    pub fn new() -> Shape {
-        Shape {
+impl LayoutImage {
+    pub fn new() -> LayoutImage {
+        LayoutImage {
             visible: None,
-            layout_type: None,
+            source: None,
             layer: None,
-            x_ref: None,
-            x_size_mode: None,
+            size_x: None,
+            size_y: None,
+            sizing: None,
+            opacity: None,
+            x: None,
+            y: None,
             x_anchor: None,
-            x0: None,
-            x1: None,
-            y_ref: None,
-            y_size_mode: None,
             y_anchor: None,
-            y0: None,
-            y1: None,
-            path: None,
-            opacity: None,
-            line: None,
-            fill_color: None,
-            fill_rule: None,
-            editable: None,
+            x_ref: None,
+            y_ref: None,
             name: None,
-            template_item_name: None
+            template_item_name: None,
         }
     }
 
-    pub fn visible(mut self, visible: bool) -> Shape {
+    pub fn visible(mut self, visible: bool) -> LayoutImage {
         self.visible = Some(visible);
         self
     }
 
-    pub fn layout_type(mut self, layout_type: LayoutType) -> Shape {
-        self.layout_type = Some(layout_type);
-        self
-    }
-
-    pub fn layer(mut self, layer: Layer) -> Shape {
-        self.layer = Some(layer);
-        self
-    }
-
-    pub fn x_ref(mut self, x_ref: String) -> Shape {
-        self.x_ref = Some(x_ref);
-        self
-    }
-
-    pub fn x_size_mode(mut self, x_size_mode: XSizeMode) -> Shape {
-        self.x_size_mode = Some(x_size_mode);
-        self
-    }
-
-    pub fn x_anchor(mut self, x_anchor: f64) -> Shape {
-        self.x_anchor = Some(x_anchor);
+    /// The url of the image, or a data uri such as those created by `source_bytes` and
+    /// `source_file`.
+    pub fn source(mut self, source: &str) -> LayoutImage {
+        self.source = Some(source.to_owned());
         self
     }
 
-    pub fn x0(mut self, x0: f64) -> Shape {
-        self.x0 = Some(x0);
+    /// Embed the image in the plot as a base64 encoded data uri, given its contents and mime type,
+    /// e.g. `"image/png"`.
+    pub fn source_bytes(mut self, bytes: &[u8], mime_type: &str) -> LayoutImage {
+        self.source = Some(format!("data:{};base64,{}", mime_type, base64::encode(bytes)));
         self
     }
 
-    pub fn x1(mut self, x1: f64) -> Shape {
-        self.x1 = Some(x1);
-        self
+    /// Embed the image file at `path` in the plot, as with `source_bytes`. The mime type is
+    /// taken from the file extension, which must be one of png, jpg, jpeg, gif, svg, webp or bmp.
+    pub fn source_file<P: AsRef<Path>>(self, path: P) -> Result<LayoutImage, Error> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_lowercase());
+        let mime_type = match extension.as_deref() {
+            Some("png") => "image/png",
+            Some("jpg") | Some("jpeg") => "image/jpeg",
+            Some("gif") => "image/gif",
+            Some("svg") => "image/svg+xml",
+            Some("webp") => "image/webp",
+            Some("bmp") => "image/bmp",
+            _ => return Err(Error::UnsupportedImageFormat(path.to_path_buf())),
+        };
+        let bytes = std::fs::read(path).map_err(|source| Error::ImageFile {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Ok(self.source_bytes(&bytes, mime_type))
     }
 
-    pub fn y_ref(mut self, y_ref: String) -> Shape {
-        self.y_ref = Some(y_ref);
+    pub fn layer(mut self, layer: Layer) -> LayoutImage {
+        self.layer = Some(layer);
         self
     }
 
-    pub fn y_size_mode(mut self, y_size_mode: YSizeMode) -> Shape {
-        self.y_size_mode = Some(y_size_mode);
+    pub fn size_x(mut self, size_x: f64) -> LayoutImage {
+        self.size_x = Some(size_x);
         self
     }
 
-    pub fn y_anchor(mut self, y_anchor: f64) -> Shape {
-        self.y_anchor = Some(y_anchor);
+    pub fn size_y(mut self, size_y: f64) -> LayoutImage {
+        self.size_y = Some(size_y);
         self
     }
 
-    pub fn y0(mut self, y0: f64) -> Shape {
-        self.y0 = Some(y0);
+    pub fn sizing(mut self, sizing: ImageSizing) -> LayoutImage {
+        self.sizing = Some(sizing);
         self
     }
 
-    pub fn y1(mut self, y1: f64) -> Shape {
-        self.y1 = Some(y1);
+    pub fn opacity(mut self, opacity: f64) -> LayoutImage {
+        self.opacity = Some(opacity);
         self
     }
 
-    pub fn path(mut self, path: String) -> Shape {
-        self.path = Some(path);
+    pub fn x<V: Serialize>(mut self, x: V) -> LayoutImage {
+        self.x = Some(serde_json::to_value(x).unwrap());
         self
     }
 
-    pub fn opacity(mut self, opacity: f64) -> Shape {
-        self.opacity = Some(opacity);
+    pub fn y<V: Serialize>(mut self, y: V) -> LayoutImage {
+        self.y = Some(serde_json::to_value(y).unwrap());
         self
     }
 
-    pub fn line(mut self, line: Line) -> Shape {
-        self.line = Some(line);
+    pub fn x_anchor(mut self, x_anchor: Anchor) -> LayoutImage {
+        self.x_anchor = Some(x_anchor);
         self
     }
 
-    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Shape {
-        self.fill_color = Some(fill_color.to_color_string());
+    pub fn y_anchor(mut self, y_anchor: Anchor) -> LayoutImage {
+        self.y_anchor = Some(y_anchor);
         self
     }
 
-    pub fn fill_rule(mut self, fill_rule: FillRule) -> Shape {
-        self.fill_rule = Some(fill_rule);
+    pub fn x_ref(mut self, x_ref: AxisReference) -> LayoutImage {
+        self.x_ref = Some(x_ref);
         self
     }
 
-    pub fn editable(mut self, editable: bool) -> Shape {
-        self.editable = Some(editable);
+    pub fn y_ref(mut self, y_ref: AxisReference) -> LayoutImage {
+        self.y_ref = Some(y_ref);
         self
     }
 
-    pub fn name(mut self, name: String) -> Shape {
-        self.name = Some(name);
+    pub fn name(mut self, name: &str) -> LayoutImage {
+        self.name = Some(name.to_owned());
         self
     }
 
-    pub fn template_item_name(mut self, template_item_name: String) -> Shape {
-        self.template_item_name = Some(template_item_name);
+    pub fn template_item_name(mut self, template_item_name: &str) -> LayoutImage {
+        self.template_item_name = Some(template_item_name.to_owned());
         self
     }
 }
 
-#[derive(Serialize, Debug)]
-pub enum LayoutType {
-    #[serde(rename="circle")]
-    Circle,
-    #[serde(rename="rect")]
-    Rect,
-    #[serde(rename="path")]
-    Path,
-    #[serde(rename="line")]
-    Line
-}
-
-#[derive(Serialize, Debug)]
-pub enum Layer {
-    #[serde(rename="above")]
-    Above,
-    #[serde(rename="below")]
-    Below
-}
-
-#[derive(Serialize, Debug)]
-pub enum XSizeMode {
-    #[serde(rename="scaled")]
-    Scaled,
-    #[serde(rename="pixel")]
-    Pixel
-}
-
-#[derive(Serialize, Debug)]
-pub enum YSizeMode {
-    #[serde(rename="scaled")]
-    Scaled,
-    #[serde(rename="pixel")]
-    Pixel
-}
-
-#[derive(Serialize, Debug)]
-pub struct Line {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    color: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    width: Option<u8>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    dash: Option<String>
-}
-
-impl Line {
-    pub fn new() -> Line {
-        Line {
-            color: None,
-            width: None,
-            dash: None
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_update_menu() {
+        let menu = UpdateMenu::new()
+            .menu_type(UpdateMenuType::Buttons)
+            .buttons(vec![
+                Button::new().label("Both").restyle(Restyle::new().set("visible", true)),
+                Button::new().label("First").update(
+                    Restyle::new()
+                        .set_per_trace("visible", vec![true, false])
+                        .set("y", vec![1, 2])
+                        .traces(vec![0, 1]),
+                    Relayout::new().set("title.text", "First"),
+                ),
+                Button::new().label("Pause").animate(Animate::pause()),
+            ]);
+        let expected = r#"{"type":"buttons","buttons":[{"label":"Both","method":"restyle","args":[{"visible":[true]}]},{"label":"First","method":"update","args":[{"visible":[true,false],"y":[[1,2]]},{"title.text":"First"},[0,1]]},{"label":"Pause","method":"animate","args":[[null],{"frame":{"duration":0.0,"redraw":false},"mode":"immediate","transition":{"duration":0.0}}]}]}"#;
+        assert_eq!(serde_json::to_string(&menu).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_annotation() {
+        let annotation = Annotation::new()
+            .text("peak")
+            .x_ref(AxisReference::axis("x2"))
+            .y_ref(AxisReference::axis("y2"))
+            .x("2020-04-01")
+            .y(4)
+            .show_arrow(true)
+            .arrow_head(2)
+            .ax_ref(AxisReference::Pixel)
+            .ay_ref(AxisReference::Paper)
+            .ax(-20)
+            .ay(0.5)
+            .click_to_show(ClickToShow::OnOff);
+        let expected = r#"{"text":"peak","showarrow":true,"arrowhead":2,"ax":-20,"ay":0.5,"axref":"pixel","ayref":"paper","xref":"x2","x":"2020-04-01","yref":"y2","y":4,"clicktoshow":"onoff"}"#;
+        let json = serde_json::to_string(&annotation).unwrap();
+        assert_eq!(json, expected);
+        let annotation: Annotation = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&annotation).unwrap(), expected);
+        assert!(serde_json::from_str::<Annotation>(r#"{"xref":"container"}"#).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "`z` is not an axis id")]
+    fn test_invalid_axis_reference() {
+        AxisReference::axis("z");
+    }
+
+    #[test]
+    fn test_serialize_slider() {
+        let slider = Slider::new()
+            .active(1)
+            .steps(vec![
+                SliderStep::new()
+                    .label("low")
+                    .value("1")
+                    .restyle(Restyle::new().set("marker.size", 5)),
+                SliderStep::new()
+                    .label("2020")
+                    .animate(Animate::new().frames(vec!["2020"])),
+            ])
+            .len_mode(ThicknessMode::Fraction)
+            .len(0.9)
+            .x_anchor(Anchor::Left)
+            .current_value(
+                CurrentValue::new()
+                    .prefix("Year: ")
+                    .x_anchor(Anchor::Right)
+                    .offset(10.0),
+            )
+            .transition(Transition::new().duration(300.0));
+        let expected = r#"{"active":1,"steps":[{"label":"low","value":"1","method":"restyle","args":[{"marker.size":[5]}]},{"label":"2020","method":"animate","args":[["2020"],{}]}],"lenmode":"fraction","len":0.9,"xanchor":"left","transition":{"duration":300.0},"currentvalue":{"xanchor":"right","offset":10.0,"prefix":"Year: "}}"#;
+        let json = serde_json::to_string(&slider).unwrap();
+        assert_eq!(json, expected);
+        let slider: Slider = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&slider).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_range_slider() {
+        let axis = Axis::new().range_slider(
+            RangeSlider::new()
+                .range(vec!["2020-01-01", "2020-06-30"])
+                .y_axis("y", RangeSliderYAxis::new().range_mode(RangeSliderRangeMode::Auto))
+                .y_axis("y2", RangeSliderYAxis::new().range_mode(RangeSliderRangeMode::Match)),
+        );
+        let expected = r#"{"rangeslider":{"range":["2020-01-01","2020-06-30"],"yaxis":{"rangemode":"auto"},"yaxis2":{"rangemode":"match"}}}"#;
+        let json = serde_json::to_string(&axis).unwrap();
+        assert_eq!(json, expected);
+        let axis: Axis = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&axis).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_range_slider_y_axis_ids() {
+        let range_slider = RangeSlider::new()
+            .y_axis("y1", RangeSliderYAxis::new().range_mode(RangeSliderRangeMode::Auto))
+            .y_axis("y12", RangeSliderYAxis::new().range_mode(RangeSliderRangeMode::Match));
+        let expected = r#"{"yaxis":{"rangemode":"auto"},"yaxis12":{"rangemode":"match"}}"#;
+        assert_eq!(serde_json::to_string(&range_slider).unwrap(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "`foo` is not a y axis id")]
+    fn test_range_slider_invalid_y_axis_id() {
+        RangeSlider::new().y_axis("foo", RangeSliderYAxis::new());
+    }
+
+    #[test]
+    fn test_serialize_indexed_subplots() {
+        let layout = Layout::new()
+            .x_axis_n(1, Axis::new().matches("x"))
+            .x_axis_n(12, Axis::new().matches("x"))
+            .y_axis_n(9, Axis::new().overlaying("y"))
+            .scene_n(3, LayoutScene::new())
+            .polar_n(10, LayoutPolar::new().hole(0.5));
+        let expected = r#"{"xaxis":{"matches":"x"},"xaxis12":{"matches":"x"},"yaxis9":{"overlaying":"y"},"scene3":{},"polar10":{"hole":0.5}}"#;
+        let json = serde_json::to_string(&layout).unwrap();
+        assert_eq!(json, expected);
+        let layout: Layout = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&layout).unwrap(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "xaxis indices start at 1")]
+    fn test_x_axis_n_zero() {
+        Layout::new().x_axis_n(0, Axis::new());
+    }
+
+    #[test]
+    fn test_serialize_layout_polar() {
+        let layout = Layout::new().polar_n(
+            2,
+            LayoutPolar::new()
+                .sector(vec![0.0, 180.0])
+                .hole(0.2)
+                .radial_axis(RadialAxis::new().range(vec![0.0, 5.0]).angle(45.0))
+                .angular_axis(
+                    AngularAxis::new()
+                        .direction(PolarDirection::Clockwise)
+                        .rotation(90.0),
+                )
+                .grid_shape(GridShape::Linear)
+                .bar_mode(BarMode::Overlay),
+        );
+        let expected = r#"{"polar2":{"sector":[0.0,180.0],"hole":0.2,"radialaxis":{"range":[0.0,5.0],"angle":45.0},"angularaxis":{"direction":"clockwise","rotation":90.0},"gridshape":"linear","barmode":"overlay"}}"#;
+        let json = serde_json::to_string(&layout).unwrap();
+        assert_eq!(json, expected);
+        let layout: Layout = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&layout).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_camera() {
+        let camera = Camera::new()
+            .eye(Eye::new(1.25, 1.25, 1.25))
+            .center(CameraCenter::new(0.0, 0.0, 0.0))
+            .up(Up::new(0.0, 0.0, 1.0))
+            .projection(CameraProjection::new().type_(ProjectionType::Orthographic));
+        let expected = r#"{"eye":{"x":1.25,"y":1.25,"z":1.25},"center":{"x":0.0,"y":0.0,"z":0.0},"up":{"x":0.0,"y":0.0,"z":1.0},"projection":{"type":"orthographic"}}"#;
+        assert_eq!(serde_json::to_string(&camera).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_scene() {
+        let layout = Layout::new()
+            .scene(
+                LayoutScene::new()
+                    .aspect_mode(AspectMode::Manual)
+                    .aspect_ratio(AspectRatio::new(1.0, 1.0, 0.5))
+                    .drag_mode(DragMode3D::False)
+                    .hover_mode(HoverMode3D::Closest),
+            )
+            .scene_n(
+                2,
+                LayoutScene::new()
+                    .camera(Camera::new().eye(Eye::new(2.0, 0.0, 0.0)))
+                    .zaxis(Axis::new().title(Title::new("depth")))
+                    .hover_mode(HoverMode3D::False),
+            );
+        let expected = r#"{"scene":{"aspectmode":"manual","aspectratio":{"x":1.0,"y":1.0,"z":0.5},"dragmode":false,"hovermode":"closest"},"scene2":{"camera":{"eye":{"x":2.0,"y":0.0,"z":0.0}},"zaxis":{"title":{"text":"depth"}},"hovermode":false}}"#;
+        let json = serde_json::to_string(&layout).unwrap();
+        assert_eq!(json, expected);
+        let layout: Layout = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&layout).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_serialize_layout_image() {
+        let image = LayoutImage::new()
+            .source("logo.png")
+            .size_x(2.0)
+            .size_y(1.0)
+            .x(0)
+            .y(1)
+            .x_ref(AxisReference::axis("x2"))
+            .y_ref(AxisReference::Paper);
+        let expected = r#"{"source":"logo.png","sizex":2.0,"sizey":1.0,"x":0,"y":1,"xref":"x2","yref":"paper"}"#;
+        let json = serde_json::to_string(&image).unwrap();
+        assert_eq!(json, expected);
+        let image: LayoutImage = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&image).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_layout_image_source() {
+        let image = LayoutImage::new().source_bytes(b"<svg/>", "image/svg+xml");
+        assert_eq!(
+            serde_json::to_string(&image).unwrap(),
+            r#"{"source":"data:image/svg+xml;base64,PHN2Zy8+"}"#
+        );
+
+        match LayoutImage::new().source_file("logo.txt") {
+            Err(Error::UnsupportedImageFormat(path)) => assert_eq!(path, Path::new("logo.txt")),
+            r => panic!("unexpected result: {:?}", r),
+        }
+        match LayoutImage::new().source_file("does_not_exist.png") {
+            Err(Error::ImageFile { path, .. }) => assert_eq!(path, Path::new("does_not_exist.png")),
+            r => panic!("unexpected result: {:?}", r),
         }
     }
-
-    pub fn color<C: Color>(mut self, color: C) -> Line {
-        self.color = Some(color.to_color_string());
-        self
-    }
-
-    pub fn width(mut self, width: u8) -> Line {
-        self.width = Some(width);
-        self
-    }
-
-    pub fn dash(mut self, dash: String) -> Line {
-        self.dash = Some(dash);
-        self
-    }
-}
-
-#[derive(Serialize, Debug)]
-pub enum FillRule {
-    #[serde(rename="evenodd")]
-    EvenOdd,
-    #[serde(rename="nonzero")]
-    Nonzero
 }
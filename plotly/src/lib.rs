@@ -8,38 +8,71 @@ extern crate num;
 extern crate rand;
 extern crate serde;
 
+pub mod animation;
+pub mod configuration;
+pub mod error;
 pub mod layout;
 pub mod plot;
+pub mod subplots;
+pub mod template;
 
 pub mod bar;
+pub mod bar_polar;
 pub mod box_plot;
 pub mod candlestick;
 pub mod common;
 pub mod contour;
 pub mod heat_map;
 pub mod histogram;
+pub mod histogram2d;
 pub mod ohlc;
+pub mod pie;
+pub mod sankey;
 pub mod scatter;
+pub mod scatter3d;
+pub mod scatter_polar;
+pub mod sunburst;
 pub mod surface;
+pub mod treemap;
+pub mod violin;
+pub mod waterfall;
 
+pub use crate::configuration::Configuration;
+pub use crate::error::Error;
 pub use crate::layout::Layout;
 pub use crate::layout::Shape;
+pub use crate::layout::Annotation;
 pub use crate::layout::LayoutType;
 pub use crate::layout::Layer;
 pub use crate::layout::XSizeMode ;
 pub use crate::layout::YSizeMode ;
 pub use crate::layout::Line;
+pub use crate::plot::AnyTrace;
+pub use crate::plot::IncludeMathJax;
+pub use crate::plot::IncludePlotlyJs;
 pub use crate::plot::Plot;
+pub use crate::subplots::Subplots;
+pub use crate::template::Template;
 
 pub use crate::bar::Bar;
+pub use crate::bar_polar::BarPolar;
 pub use crate::box_plot::BoxPlot;
 pub use crate::candlestick::Candlestick;
 pub use crate::contour::Contour;
 pub use crate::heat_map::HeatMap;
 pub use crate::histogram::Histogram;
+pub use crate::histogram2d::{Histogram2d, Histogram2dContour};
 pub use crate::ohlc::Ohlc;
+pub use crate::pie::Pie;
+pub use crate::sankey::Sankey;
 pub use crate::scatter::Scatter;
+pub use crate::scatter3d::Scatter3D;
+pub use crate::scatter_polar::ScatterPolar;
+pub use crate::sunburst::Sunburst;
 pub use crate::surface::Surface;
+pub use crate::treemap::Treemap;
+pub use crate::violin::Violin;
+pub use crate::waterfall::Waterfall;
 
 pub use crate::common::color::NamedColor;
 pub use crate::common::color::Rgb;
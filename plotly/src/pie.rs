@@ -0,0 +1,436 @@
+//! Pie chart
+
+use crate::common::color::Color;
+use crate::common::{Dim, Domain, Font, Label, Line, PlotType, TextPosition, Title, Visible};
+use crate::private;
+use crate::Trace;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum PieDirection {
+    #[serde(rename = "clockwise")]
+    Clockwise,
+    #[serde(rename = "counterclockwise")]
+    CounterClockwise,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum InsideTextOrientation {
+    #[serde(rename = "horizontal")]
+    Horizontal,
+    #[serde(rename = "radial")]
+    Radial,
+    #[serde(rename = "tangential")]
+    Tangential,
+    #[serde(rename = "auto")]
+    Auto,
+}
+
+/// The information shown on the sectors of a `Pie` with `Pie::text_info`, or on hover with
+/// `Pie::hover_info`. Several flags are combined, e.g. `vec![PieInfo::Label, PieInfo::Percent]`.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum PieInfo {
+    #[serde(rename = "label")]
+    Label,
+    #[serde(rename = "text")]
+    Text,
+    #[serde(rename = "value")]
+    Value,
+    #[serde(rename = "percent")]
+    Percent,
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "none")]
+    None,
+    /// Only valid for `Pie::hover_info`.
+    #[serde(rename = "skip")]
+    Skip,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct PieMarker {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    colors: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+}
+
+impl PieMarker {
+    pub fn new() -> PieMarker {
+        PieMarker {
+            colors: None,
+            line: None,
+        }
+    }
+
+    pub fn colors<C: Color>(mut self, colors: Vec<C>) -> PieMarker {
+        let colors = private::to_color_array(colors);
+        self.colors = Some(colors);
+        self
+    }
+
+    pub fn line(mut self, line: Line) -> PieMarker {
+        self.line = Some(line);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Pie<V>
+where
+    V: Serialize,
+{
+    r#type: PlotType,
+    values: Vec<V>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    labels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label0: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dlabel: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textinfo")]
+    text_info: Option<private::FlagList<PieInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<private::FlagList<PieInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<PieMarker>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textposition")]
+    text_position: Option<Dim<TextPosition>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "insidetextorientation"
+    )]
+    inside_text_orientation: Option<InsideTextOrientation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    automargin: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<Title>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hole: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<PieDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pull: Option<Dim<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "scalegroup")]
+    scale_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl<V> Pie<V>
+where
+    V: Serialize,
+{
+    pub fn new(values: Vec<V>) -> Box<Pie<V>> {
+        Box::new(Pie {
+            r#type: PlotType::Pie,
+            values,
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            ids: None,
+            labels: None,
+            label0: None,
+            dlabel: None,
+            text: None,
+            text_info: None,
+            text_template: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            marker: None,
+            text_position: None,
+            text_font: None,
+            inside_text_font: None,
+            outside_text_font: None,
+            inside_text_orientation: None,
+            automargin: None,
+            title: None,
+            domain: None,
+            hole: None,
+            sort: None,
+            direction: None,
+            rotation: None,
+            pull: None,
+            scale_group: None,
+            hover_label: None,
+        })
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Pie<V>> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<Pie<V>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Pie<V>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<B: Into<Visible>>(mut self, visible: B) -> Box<Pie<V>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Pie<V>> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Pie<V>> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Pie<V>> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Pie<V>> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn labels<S: AsRef<str>>(mut self, labels: Vec<S>) -> Box<Pie<V>> {
+        let labels = private::owned_string_vector(labels);
+        self.labels = Some(labels);
+        Box::new(self)
+    }
+
+    pub fn label0(mut self, label0: f64) -> Box<Pie<V>> {
+        self.label0 = Some(label0);
+        Box::new(self)
+    }
+
+    pub fn dlabel(mut self, dlabel: f64) -> Box<Pie<V>> {
+        self.dlabel = Some(dlabel);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Pie<V>> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Pie<V>> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn text_info(mut self, text_info: Vec<PieInfo>) -> Box<Pie<V>> {
+        self.text_info = Some(private::FlagList { flags: text_info });
+        Box::new(self)
+    }
+
+    pub fn text_template(mut self, text_template: &str) -> Box<Pie<V>> {
+        self.text_template = Some(Dim::Scalar(text_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_template_array<S: AsRef<str>>(mut self, text_template: Vec<S>) -> Box<Pie<V>> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Pie<V>> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Pie<V>> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: Vec<PieInfo>) -> Box<Pie<V>> {
+        self.hover_info = Some(private::FlagList { flags: hover_info });
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Pie<V>> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Pie<V>> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: PieMarker) -> Box<Pie<V>> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn text_position(mut self, text_position: TextPosition) -> Box<Pie<V>> {
+        self.text_position = Some(Dim::Scalar(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_position_array(mut self, text_position: Vec<TextPosition>) -> Box<Pie<V>> {
+        self.text_position = Some(Dim::Vector(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<Pie<V>> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    pub fn inside_text_font(mut self, inside_text_font: Font) -> Box<Pie<V>> {
+        self.inside_text_font = Some(inside_text_font);
+        Box::new(self)
+    }
+
+    pub fn outside_text_font(mut self, outside_text_font: Font) -> Box<Pie<V>> {
+        self.outside_text_font = Some(outside_text_font);
+        Box::new(self)
+    }
+
+    pub fn inside_text_orientation(
+        mut self,
+        inside_text_orientation: InsideTextOrientation,
+    ) -> Box<Pie<V>> {
+        self.inside_text_orientation = Some(inside_text_orientation);
+        Box::new(self)
+    }
+
+    pub fn automargin(mut self, automargin: bool) -> Box<Pie<V>> {
+        self.automargin = Some(automargin);
+        Box::new(self)
+    }
+
+    pub fn title(mut self, title: Title) -> Box<Pie<V>> {
+        self.title = Some(title);
+        Box::new(self)
+    }
+
+    pub fn domain(mut self, domain: Domain) -> Box<Pie<V>> {
+        self.domain = Some(domain);
+        Box::new(self)
+    }
+
+    pub fn hole(mut self, hole: f64) -> Box<Pie<V>> {
+        self.hole = Some(hole);
+        Box::new(self)
+    }
+
+    pub fn sort(mut self, sort: bool) -> Box<Pie<V>> {
+        self.sort = Some(sort);
+        Box::new(self)
+    }
+
+    pub fn direction(mut self, direction: PieDirection) -> Box<Pie<V>> {
+        self.direction = Some(direction);
+        Box::new(self)
+    }
+
+    pub fn rotation(mut self, rotation: f64) -> Box<Pie<V>> {
+        self.rotation = Some(rotation);
+        Box::new(self)
+    }
+
+    pub fn pull(mut self, pull: f64) -> Box<Pie<V>> {
+        self.pull = Some(Dim::Scalar(pull));
+        Box::new(self)
+    }
+
+    pub fn pull_array(mut self, pull: Vec<f64>) -> Box<Pie<V>> {
+        self.pull = Some(Dim::Vector(pull));
+        Box::new(self)
+    }
+
+    pub fn scale_group(mut self, scale_group: &str) -> Box<Pie<V>> {
+        self.scale_group = Some(scale_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Pie<V>> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+}
+
+impl<V> Trace for Pie<V>
+where
+    V: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_pie() {
+        let trace = Pie::new(vec![2, 3])
+            .labels(vec!["a", "b"])
+            .text_info(vec![PieInfo::Label, PieInfo::Percent])
+            .hover_info(vec![PieInfo::Skip])
+            .inside_text_orientation(InsideTextOrientation::Radial)
+            .domain(Domain::new().x(vec![0.0, 0.5]))
+            .hole(0.4)
+            .direction(PieDirection::CounterClockwise)
+            .pull_array(vec![0.1, 0.0]);
+        let expected = r#"{"type":"pie","values":[2,3],"labels":["a","b"],"textinfo":"label+percent","hoverinfo":"skip","insidetextorientation":"radial","domain":{"x":[0.0,0.5]},"hole":0.4,"direction":"counterclockwise","pull":[0.1,0.0]}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
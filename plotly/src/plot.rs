@@ -7,12 +7,33 @@ use std::env;
 use std::fs::File;
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Output};
 
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::animation::{AnimationOptions, Frame};
+use crate::common::PlotType;
+use crate::subplots::SubplotGrid;
+use crate::Configuration;
+use crate::Error;
 use crate::Layout;
+use crate::{
+    Bar, BarPolar, BoxPlot, Candlestick, Contour, HeatMap, Histogram, Histogram2d,
+    Histogram2dContour, Ohlc, Pie, Sankey, Scatter, Scatter3D, ScatterPolar, Sunburst, Surface,
+    Treemap, Violin, Waterfall,
+};
 
 const PLOTLY_JS: &str = "plotly-1.52.2.min.js";
 
+/// The CDN location of the plotly.js version bundled with this crate.
+pub const PLOTLY_JS_CDN: &str = "https://cdn.plot.ly/plotly-1.52.2.min.js";
+
+/// The CDN location of the MathJax version used for rendering LaTeX in plots.
+pub const MATHJAX_CDN: &str =
+    "https://cdnjs.cloudflare.com/ajax/libs/mathjax/2.7.5/MathJax.js?config=TeX-AMS-MML_SVG";
+
 #[derive(Template)]
 #[template(path = "plotly-1.52.2.min.js", escape = "none")]
 struct PlotlyJs;
@@ -21,11 +42,69 @@ struct PlotlyJs;
 #[template(path = "plot.html", escape = "none")]
 struct PlotTemplate<'a> {
     plot_data: &'a str,
-    plotly_javascript: &'a str,
+    plotly_script: &'a str,
+    mathjax_script: &'a str,
     export_image: bool,
     image_type: &'a str,
     image_width: usize,
     image_height: usize,
+    animated: bool,
+}
+
+#[derive(Template)]
+#[template(path = "inline_plot.html", escape = "none")]
+struct InlinePlotTemplate<'a> {
+    plot_data: &'a str,
+    plot_div_id: &'a str,
+    animated: bool,
+}
+
+#[derive(Template)]
+#[template(path = "notebook_plot.html", escape = "none")]
+struct NotebookPlotTemplate<'a> {
+    plot_data: &'a str,
+    plot_div_id: &'a str,
+    plotly_cdn: &'a str,
+    animated: bool,
+}
+
+/// Determines how the plotly.js library is included in the html rendered by `Plot`.
+#[derive(Debug, Clone)]
+pub enum IncludePlotlyJs {
+    /// Embed the bundled plotly.js in the html; the result works offline but is ~3 MB in size.
+    Inline,
+    /// Load plotly.js from the given CDN url, see `PLOTLY_JS_CDN`.
+    Cdn(String),
+    /// Load plotly.js from a path relative to the location of the html file.
+    RelativePath(String),
+    /// Do not include plotly.js; the page displaying the plot is expected to provide it.
+    None,
+}
+
+impl IncludePlotlyJs {
+    /// Load the plotly.js version bundled with this crate from the official CDN.
+    pub fn cdn() -> IncludePlotlyJs {
+        IncludePlotlyJs::Cdn(PLOTLY_JS_CDN.to_owned())
+    }
+}
+
+/// Determines how MathJax, which is used to render LaTeX in plots, is included in the html
+/// rendered by `Plot`.
+#[derive(Debug, Clone)]
+pub enum IncludeMathJax {
+    /// Load MathJax from the given CDN url, see `MATHJAX_CDN`.
+    Cdn(String),
+    /// Load MathJax from a path relative to the location of the html file.
+    RelativePath(String),
+    /// Do not include MathJax; LaTeX in the plot will not be rendered.
+    None,
+}
+
+impl IncludeMathJax {
+    /// Load MathJax from the default CDN.
+    pub fn cdn() -> IncludeMathJax {
+        IncludeMathJax::Cdn(MATHJAX_CDN.to_owned())
+    }
 }
 
 /// A struct that implements `Trace` can be serialized to json format that is understood by Plotly.js.
@@ -33,13 +112,108 @@ pub trait Trace {
     fn serialize(&self) -> String;
 }
 
+/// Any of the supported trace types, selected by the `type` field of its JSON representation.
+///
+/// `AnyTrace` is used to accept traces as JSON, validate them against the typed trace structs and
+/// re-emit them, e.g. as part of a `Plot`. Data arrays are kept as `serde_json::Value`s, as they
+/// may contain numbers, strings, dates and `null`s for missing values. As in Plotly.js, a trace
+/// without a `type` is a scatter trace.
+///
+/// # Examples
+///
+/// ```
+/// use plotly::{AnyTrace, Plot};
+///
+/// let trace: AnyTrace = serde_json::from_str(r#"{"type": "bar", "x": ["a", "b"], "y": [1, 2]}"#)
+///     .expect("invalid trace");
+/// let mut plot = Plot::new();
+/// plot.add_trace(Box::new(trace));
+/// ```
+#[derive(Serialize, Debug)]
+#[serde(untagged)]
+pub enum AnyTrace {
+    Scatter(Box<Scatter<Value, Value>>),
+    Scatter3D(Box<Scatter3D<Value, Value, Value>>),
+    ScatterPolar(Box<ScatterPolar<Value, Value>>),
+    Bar(Box<Bar<Value, Value>>),
+    BarPolar(Box<BarPolar<Value, Value>>),
+    BoxPlot(Box<BoxPlot<Value, Value>>),
+    Candlestick(Box<Candlestick<Value, Value>>),
+    Contour(Box<Contour<Value, Value, Value>>),
+    HeatMap(Box<HeatMap<Value, Value, Value>>),
+    Histogram(Box<Histogram<Value>>),
+    Histogram2d(Box<Histogram2d<Value, Value>>),
+    Histogram2dContour(Box<Histogram2dContour<Value, Value>>),
+    Ohlc(Box<Ohlc<Value, Value>>),
+    Pie(Box<Pie<Value>>),
+    Sankey(Box<Sankey>),
+    Sunburst(Box<Sunburst>),
+    Surface(Box<Surface<Value, Value, Value>>),
+    Treemap(Box<Treemap>),
+    Violin(Box<Violin<Value, Value>>),
+    Waterfall(Box<Waterfall<Value, Value>>),
+}
+
+impl<'de> Deserialize<'de> for AnyTrace {
+    fn deserialize<D>(deserializer: D) -> Result<AnyTrace, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut trace = Value::deserialize(deserializer)?;
+        if let Some(obj) = trace.as_object_mut() {
+            obj.entry("type").or_insert_with(|| Value::from("scatter"));
+        }
+        let plot_type = PlotType::deserialize(&trace["type"]).map_err(de::Error::custom)?;
+        let trace = match plot_type {
+            PlotType::Scatter => Box::<Scatter<_, _>>::deserialize(trace).map(AnyTrace::Scatter),
+            PlotType::Scatter3D => {
+                Box::<Scatter3D<_, _, _>>::deserialize(trace).map(AnyTrace::Scatter3D)
+            }
+            PlotType::ScatterPolar => {
+                Box::<ScatterPolar<_, _>>::deserialize(trace).map(AnyTrace::ScatterPolar)
+            }
+            PlotType::Bar => Box::<Bar<_, _>>::deserialize(trace).map(AnyTrace::Bar),
+            PlotType::BarPolar => Box::<BarPolar<_, _>>::deserialize(trace).map(AnyTrace::BarPolar),
+            PlotType::Box => Box::<BoxPlot<_, _>>::deserialize(trace).map(AnyTrace::BoxPlot),
+            PlotType::Candlestick => {
+                Box::<Candlestick<_, _>>::deserialize(trace).map(AnyTrace::Candlestick)
+            }
+            PlotType::Contour => Box::<Contour<_, _, _>>::deserialize(trace).map(AnyTrace::Contour),
+            PlotType::HeatMap => Box::<HeatMap<_, _, _>>::deserialize(trace).map(AnyTrace::HeatMap),
+            PlotType::Histogram => Box::<Histogram<_>>::deserialize(trace).map(AnyTrace::Histogram),
+            PlotType::Histogram2d => {
+                Box::<Histogram2d<_, _>>::deserialize(trace).map(AnyTrace::Histogram2d)
+            }
+            PlotType::Histogram2dContour => Box::<Histogram2dContour<_, _>>::deserialize(trace)
+                .map(AnyTrace::Histogram2dContour),
+            PlotType::Ohlc => Box::<Ohlc<_, _>>::deserialize(trace).map(AnyTrace::Ohlc),
+            PlotType::Pie => Box::<Pie<_>>::deserialize(trace).map(AnyTrace::Pie),
+            PlotType::Sankey => Box::<Sankey>::deserialize(trace).map(AnyTrace::Sankey),
+            PlotType::Sunburst => Box::<Sunburst>::deserialize(trace).map(AnyTrace::Sunburst),
+            PlotType::Surface => Box::<Surface<_, _, _>>::deserialize(trace).map(AnyTrace::Surface),
+            PlotType::Treemap => Box::<Treemap>::deserialize(trace).map(AnyTrace::Treemap),
+            PlotType::Violin => Box::<Violin<_, _>>::deserialize(trace).map(AnyTrace::Violin),
+            PlotType::Waterfall => {
+                Box::<Waterfall<_, _>>::deserialize(trace).map(AnyTrace::Waterfall)
+            }
+        };
+        trace.map_err(de::Error::custom)
+    }
+}
+
+impl Trace for AnyTrace {
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
 /// Plot is a container for structs that implement the `Trace` trait. Optionally a `Layout` can
 /// also be specified. Its function is to serialize `Trace`s and the `Layout` in html format and
 /// display and/or persist the resulting plot.
 ///
 /// # Examples
 ///
-/// ```
+/// ```no_run
 /// extern crate plotly;
 /// use plotly::common::Mode;
 /// use plotly::{Plot, Scatter};
@@ -68,25 +242,26 @@ pub trait Trace {
 pub struct Plot {
     traces: Vec<Box<dyn Trace>>,
     layout: Option<Layout>,
+    configuration: Configuration,
+    include_plotly_js: IncludePlotlyJs,
+    include_mathjax: IncludeMathJax,
+    subplot_grid: Option<SubplotGrid>,
+    frames: Vec<Frame>,
+    animation_options: Option<AnimationOptions>,
 }
 
-const DEFAULT_HTML_APP_NOT_FOUND: &str = r#"Could not find default application for HTML files.
-Consider using the `to_html` method to save the plot instead. If using the `orca` feature the following
-additional formats are available accessed by following methods:
-- to_png
-- to_jpeg
-- to_webp
-- to_svg
-- to_pdf
-- to_eps
-"#;
-
 impl Plot {
     /// Create a new `Plot`.
     pub fn new() -> Plot {
         Plot {
             traces: Vec::with_capacity(1),
             layout: None,
+            configuration: Configuration::new().responsive(true),
+            include_plotly_js: IncludePlotlyJs::Inline,
+            include_mathjax: IncludeMathJax::cdn(),
+            subplot_grid: None,
+            frames: Vec::new(),
+            animation_options: None,
         }
     }
 
@@ -95,90 +270,150 @@ impl Plot {
         self.traces.push(trace);
     }
 
+    /// Add a `Trace` to the subplot in the given row and column of a `Plot` built with `Subplots`.
+    /// Rows and columns are numbered from 1.
+    ///
+    /// Panics if the `Plot` was not built with `Subplots` or the cell is outside the grid; see
+    /// `Plot::try_add_trace_at` for a non-panicking alternative.
+    pub fn add_trace_at(&mut self, trace: Box<dyn Trace>, row: usize, col: usize) {
+        if let Err(e) = self.try_add_trace_at(trace, row, col) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Same as `Plot::add_trace_at`, but returns an `Error` instead of panicking.
+    pub fn try_add_trace_at(
+        &mut self,
+        trace: Box<dyn Trace>,
+        row: usize,
+        col: usize,
+    ) -> Result<(), Error> {
+        let trace = self
+            .subplot_grid()?
+            .place(trace.as_ref(), row, col, false)?;
+        self.traces.push(trace);
+        Ok(())
+    }
+
+    /// Add a `Trace` against the secondary y axis of the subplot in the given row and column, which
+    /// must have been enabled with `SubplotSpec::secondary_y`.
+    ///
+    /// Panics if the `Plot` was not built with `Subplots`, the cell is outside the grid or it has
+    /// no secondary y axis; see `Plot::try_add_secondary_y_trace_at` for a non-panicking
+    /// alternative.
+    pub fn add_secondary_y_trace_at(&mut self, trace: Box<dyn Trace>, row: usize, col: usize) {
+        if let Err(e) = self.try_add_secondary_y_trace_at(trace, row, col) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Same as `Plot::add_secondary_y_trace_at`, but returns an `Error` instead of panicking.
+    pub fn try_add_secondary_y_trace_at(
+        &mut self,
+        trace: Box<dyn Trace>,
+        row: usize,
+        col: usize,
+    ) -> Result<(), Error> {
+        let trace = self.subplot_grid()?.place(trace.as_ref(), row, col, true)?;
+        self.traces.push(trace);
+        Ok(())
+    }
+
+    pub(crate) fn set_subplot_grid(&mut self, subplot_grid: SubplotGrid) {
+        self.subplot_grid = Some(subplot_grid);
+    }
+
+    fn subplot_grid(&self) -> Result<&SubplotGrid, Error> {
+        self.subplot_grid.as_ref().ok_or_else(|| {
+            Error::InvalidSubplot("the plot was not built with `Subplots`".to_owned())
+        })
+    }
+
+    /// Add an animation `Frame` to the `Plot`.
+    pub fn add_frame(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    /// Play the frames of the `Plot` once it is shown, with the given options. Without them the
+    /// frames are only played by `Animate` buttons and slider steps.
+    pub fn set_animation_options(&mut self, animation_options: AnimationOptions) {
+        self.animation_options = Some(animation_options);
+    }
+
     /// Set the `Layout` to be used by `Plot`.
     pub fn set_layout(&mut self, layout: Layout) {
         self.layout = Some(layout);
     }
 
+    /// Set the `Configuration` to be used by `Plot`.
+    ///
+    /// The default configuration only makes the plot responsive to window resizing; this setting is
+    /// replaced, so set `Configuration::responsive` again if it is still desired.
+    pub fn set_configuration(&mut self, configuration: Configuration) {
+        self.configuration = configuration;
+    }
+
+    /// Set how plotly.js is included in the rendered html. Defaults to `IncludePlotlyJs::Inline`.
+    pub fn set_include_plotly_js(&mut self, include_plotly_js: IncludePlotlyJs) {
+        self.include_plotly_js = include_plotly_js;
+    }
+
+    /// Set how MathJax is included in the rendered html. Defaults to `IncludeMathJax::cdn()`.
+    pub fn set_include_mathjax(&mut self, include_mathjax: IncludeMathJax) {
+        self.include_mathjax = include_mathjax;
+    }
+
     /// Renders the contents of the `Plot` and displays them in the system default browser.
     ///
     /// This will serialize the `Trace`s and `Layout` in an html page which is saved in the temp
     /// directory. For example on Linux it will generate a file `plotly_<22 random characters>.html`
     /// in the /tmp directory.
+    ///
+    /// Panics if the html cannot be written or the default browser cannot be launched; see
+    /// `Plot::try_show` for a non-panicking alternative.
     pub fn show(&self) {
-        let rendered = self.render(false, "", 0, 0);
-        let rendered = rendered.as_bytes();
-        let mut temp = env::temp_dir();
-
-        let mut plot_name = rand::thread_rng()
-            .sample_iter(&rand::distributions::Alphanumeric)
-            .take(22)
-            .collect::<String>();
-        plot_name.push_str(".html");
-        plot_name = format!("plotly_{}", plot_name);
-
-        temp.push(plot_name);
-        let temp_path = temp.to_str().unwrap();
-        {
-            let mut file = File::create(temp_path).unwrap();
-            file.write_all(rendered)
-                .expect("failed to write html output");
-            file.flush().unwrap();
+        if let Err(e) = self.try_show() {
+            panic!("{}", e);
         }
+    }
 
-        Plot::show_with_default_app(temp_path);
+    /// Same as `Plot::show`, but returns an `Error` instead of panicking.
+    pub fn try_show(&self) -> Result<(), Error> {
+        let rendered = self.render(false, "", 0, 0)?;
+        let temp_path = Plot::write_temp_html(&rendered, "plotly_")?;
+        Plot::show_with_default_app(&temp_path)
     }
 
     /// Renders the contents of the `Plot`, creates a png raster and displays it in the system default browser.
     ///
     /// To save the resulting png right-click on the resulting image and select `Save As...`.
     pub fn show_png(&self, width: usize, height: usize) {
-        let rendered = self.render(true, "png", width, height);
-        let rendered = rendered.as_bytes();
-        let mut temp = env::temp_dir();
-
-        let mut plot_name = rand::thread_rng()
-            .sample_iter(&rand::distributions::Alphanumeric)
-            .take(22)
-            .collect::<String>();
-        plot_name.push_str(".html");
-
-        temp.push(plot_name);
-        let temp_path = temp.to_str().unwrap();
-        {
-            let mut file = File::create(temp_path).unwrap();
-            file.write_all(rendered)
-                .expect("failed to write html output");
-            file.flush().unwrap();
+        if let Err(e) = self.try_show_png(width, height) {
+            panic!("{}", e);
         }
+    }
 
-        Plot::show_with_default_app(temp_path);
+    /// Same as `Plot::show_png`, but returns an `Error` instead of panicking.
+    pub fn try_show_png(&self, width: usize, height: usize) -> Result<(), Error> {
+        let rendered = self.render(true, "png", width, height)?;
+        let temp_path = Plot::write_temp_html(&rendered, "")?;
+        Plot::show_with_default_app(&temp_path)
     }
 
     /// Renders the contents of the `Plot`, creates a jpeg raster and displays it in the system default browser.
     ///
     /// To save the resulting png right-click on the resulting image and select `Save As...`.
     pub fn show_jpeg(&self, width: usize, height: usize) {
-        let rendered = self.render(true, "jpg", width, height);
-        let rendered = rendered.as_bytes();
-        let mut temp = env::temp_dir();
-
-        let mut plot_name = rand::thread_rng()
-            .sample_iter(&rand::distributions::Alphanumeric)
-            .take(22)
-            .collect::<String>();
-        plot_name.push_str(".html");
-
-        temp.push(plot_name);
-        let temp_path = temp.to_str().unwrap();
-        {
-            let mut file = File::create(temp_path).unwrap();
-            file.write_all(rendered)
-                .expect("failed to write html output");
-            file.flush().unwrap();
+        if let Err(e) = self.try_show_jpeg(width, height) {
+            panic!("{}", e);
         }
+    }
 
-        Plot::show_with_default_app(temp_path);
+    /// Same as `Plot::show_jpeg`, but returns an `Error` instead of panicking.
+    pub fn try_show_jpeg(&self, width: usize, height: usize) -> Result<(), Error> {
+        let rendered = self.render(true, "jpg", width, height)?;
+        let temp_path = Plot::write_temp_html(&rendered, "")?;
+        Plot::show_with_default_app(&temp_path)
     }
 
     /// Renders the contents of the `Plot` and displays it in the system default browser.
@@ -186,81 +421,337 @@ impl Plot {
     /// In contrast to `Plot::show()` this will save the resulting html in a user specified location
     /// instead of the system temp directory.
     pub fn to_html<P: AsRef<Path>>(&self, filename: P) {
-        let rendered = self.render(false, "", 0, 0);
-        let rendered = rendered.as_bytes();
-        let mut file = File::create(filename.as_ref()).unwrap();
-        file.write_all(rendered)
-            .expect("failed to write html output");
+        if let Err(e) = self.try_to_html(filename) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Same as `Plot::to_html`, but returns an `Error` instead of panicking.
+    pub fn try_to_html<P: AsRef<Path>>(&self, filename: P) -> Result<(), Error> {
+        let file = File::create(filename.as_ref())?;
+        self.write_html(file)
+    }
+
+    /// Renders the contents of the `Plot` to a standalone html page and returns it as a `String`.
+    pub fn to_html_string(&self) -> String {
+        match self.try_to_html_string() {
+            Ok(rendered) => rendered,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Same as `Plot::to_html_string`, but returns an `Error` instead of panicking.
+    pub fn try_to_html_string(&self) -> Result<String, Error> {
+        self.render(false, "", 0, 0)
+    }
+
+    /// Renders the contents of the `Plot` to a standalone html page and writes it to `writer`.
+    pub fn write_html<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        let rendered = self.render(false, "", 0, 0)?;
+        writer.write_all(rendered.as_bytes())?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Renders the contents of the `Plot` to an html `<div>` fragment that can be embedded in a
+    /// larger page.
+    ///
+    /// The fragment does not include plotly.js, so the page it is embedded in has to load it. If
+    /// `plot_div_id` is `None` a random id is generated for the `<div>`; give each plot on the same
+    /// page a distinct id. A given `plot_div_id` must start with an ASCII letter followed by ASCII
+    /// letters, digits, `-` or `_`.
+    pub fn to_inline_html(&self, plot_div_id: Option<&str>) -> String {
+        match self.try_to_inline_html(plot_div_id) {
+            Ok(rendered) => rendered,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Same as `Plot::to_inline_html`, but returns an `Error` instead of panicking.
+    pub fn try_to_inline_html(&self, plot_div_id: Option<&str>) -> Result<String, Error> {
+        let plot_div_id = match plot_div_id {
+            Some(id) if Plot::is_valid_div_id(id) => id.to_owned(),
+            Some(id) => return Err(Error::InvalidDivId(id.to_owned())),
+            None => Plot::random_div_id(),
+        };
+        let plot_data = self.render_plot_data();
+        let tmpl = InlinePlotTemplate {
+            plot_data: plot_data.as_str(),
+            plot_div_id: plot_div_id.as_str(),
+            animated: !self.frames.is_empty(),
+        };
+        Ok(tmpl.render()?)
     }
 
     /// Saves the `Plot` to png format.
     #[cfg(feature = "orca")]
     pub fn to_png<P: AsRef<Path>>(&self, filename: P, width: usize, height: usize) {
-        let orca = plotly_orca::Orca::from(Plot::plotly_js_path());
+        if let Err(e) = self.try_to_png(filename, width, height) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Same as `Plot::to_png`, but returns an `Error` instead of panicking.
+    #[cfg(feature = "orca")]
+    pub fn try_to_png<P: AsRef<Path>>(
+        &self,
+        filename: P,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        let orca = plotly_orca::Orca::try_from(Plot::plotly_js_path())?;
         let rendered = self.render_orca_format();
-        orca.save_png(filename.as_ref(), &rendered, width, height);
+        orca.try_save_png(filename.as_ref(), &rendered, width, height)?;
+        Ok(())
     }
 
     /// Saves the `Plot` to jpeg format.
     #[cfg(feature = "orca")]
     pub fn to_jpeg<P: AsRef<Path>>(&self, filename: P, width: usize, height: usize) {
-        let orca = plotly_orca::Orca::from(Plot::plotly_js_path());
+        if let Err(e) = self.try_to_jpeg(filename, width, height) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Same as `Plot::to_jpeg`, but returns an `Error` instead of panicking.
+    #[cfg(feature = "orca")]
+    pub fn try_to_jpeg<P: AsRef<Path>>(
+        &self,
+        filename: P,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        let orca = plotly_orca::Orca::try_from(Plot::plotly_js_path())?;
         let rendered = self.render_orca_format();
-        orca.save_jpeg(filename.as_ref(), &rendered, width, height);
+        orca.try_save_jpeg(filename.as_ref(), &rendered, width, height)?;
+        Ok(())
     }
 
     /// Saves the `Plot` to webp format.
     #[cfg(feature = "orca")]
     pub fn to_webp<P: AsRef<Path>>(&self, filename: P, width: usize, height: usize) {
-        let orca = plotly_orca::Orca::from(Plot::plotly_js_path());
+        if let Err(e) = self.try_to_webp(filename, width, height) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Same as `Plot::to_webp`, but returns an `Error` instead of panicking.
+    #[cfg(feature = "orca")]
+    pub fn try_to_webp<P: AsRef<Path>>(
+        &self,
+        filename: P,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        let orca = plotly_orca::Orca::try_from(Plot::plotly_js_path())?;
         let rendered = self.render_orca_format();
-        orca.save_webp(filename.as_ref(), &rendered, width, height);
+        orca.try_save_webp(filename.as_ref(), &rendered, width, height)?;
+        Ok(())
     }
 
     /// Saves the `Plot` to svg format.
     #[cfg(feature = "orca")]
     pub fn to_svg<P: AsRef<Path>>(&self, filename: P, width: usize, height: usize) {
-        let orca = plotly_orca::Orca::from(Plot::plotly_js_path());
+        if let Err(e) = self.try_to_svg(filename, width, height) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Same as `Plot::to_svg`, but returns an `Error` instead of panicking.
+    #[cfg(feature = "orca")]
+    pub fn try_to_svg<P: AsRef<Path>>(
+        &self,
+        filename: P,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        let orca = plotly_orca::Orca::try_from(Plot::plotly_js_path())?;
         let rendered = self.render_orca_format();
-        orca.save_svg(filename.as_ref(), &rendered, width, height);
+        orca.try_save_svg(filename.as_ref(), &rendered, width, height)?;
+        Ok(())
     }
 
     /// Saves the `Plot` to pdf format.
     #[cfg(feature = "orca")]
     pub fn to_pdf<P: AsRef<Path>>(&self, filename: P, width: usize, height: usize) {
-        let orca = plotly_orca::Orca::from(Plot::plotly_js_path());
+        if let Err(e) = self.try_to_pdf(filename, width, height) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Same as `Plot::to_pdf`, but returns an `Error` instead of panicking.
+    #[cfg(feature = "orca")]
+    pub fn try_to_pdf<P: AsRef<Path>>(
+        &self,
+        filename: P,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        let orca = plotly_orca::Orca::try_from(Plot::plotly_js_path())?;
         let rendered = self.render_orca_format();
-        orca.save_pdf(filename.as_ref(), &rendered, width, height);
+        orca.try_save_pdf(filename.as_ref(), &rendered, width, height)?;
+        Ok(())
     }
 
     /// Saves the `Plot` to eps format.
     #[cfg(feature = "orca")]
     pub fn to_eps<P: AsRef<Path>>(&self, filename: P, width: usize, height: usize) {
-        let orca = plotly_orca::Orca::from(Plot::plotly_js_path());
+        if let Err(e) = self.try_to_eps(filename, width, height) {
+            panic!("{}", e);
+        }
+    }
+
+    /// Same as `Plot::to_eps`, but returns an `Error` instead of panicking.
+    #[cfg(feature = "orca")]
+    pub fn try_to_eps<P: AsRef<Path>>(
+        &self,
+        filename: P,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        let orca = plotly_orca::Orca::try_from(Plot::plotly_js_path())?;
         let rendered = self.render_orca_format();
-        orca.save_eps(filename.as_ref(), &rendered, width, height);
+        orca.try_save_eps(filename.as_ref(), &rendered, width, height)?;
+        Ok(())
+    }
+
+    /// Serializes the `Plot` to a Plotly JSON figure of the form `{"data": [...], "layout": {...}}`.
+    ///
+    /// The result can be loaded by `Plot::from_json`, by Plotly.js and by the other Plotly
+    /// libraries, e.g. using `plotly.io.from_json` in Python.
+    pub fn to_json(&self) -> String {
+        self.to_json_value().to_string()
+    }
+
+    /// Deserializes a Plotly JSON figure, such as one produced by `Plot::to_json` or exported from
+    /// Python Plotly, into a `Plot` with typed `Trace`s and `Layout`.
+    ///
+    /// Traces without a `type` are treated as scatter traces, as in Plotly.js. An `Error::Json` is
+    /// returned if the figure contains a trace type or attribute that cannot be represented.
+    pub fn from_json(json: &str) -> Result<Plot, Error> {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Figure {
+            #[serde(default)]
+            data: Vec<AnyTrace>,
+            layout: Option<Layout>,
+            #[serde(default)]
+            frames: Vec<Frame>,
+            config: Option<Configuration>,
+        }
+
+        let figure: Figure = serde_json::from_str(json)?;
+        let mut plot = Plot::new();
+        for trace in figure.data {
+            plot.add_trace(Box::new(trace));
+        }
+        if let Some(layout) = figure.layout {
+            plot.set_layout(layout);
+        }
+        for frame in figure.frames {
+            plot.add_frame(frame);
+        }
+        if let Some(configuration) = figure.config {
+            plot.set_configuration(configuration);
+        }
+        Ok(plot)
+    }
+
+    /// Displays the `Plot` inline in a Jupyter notebook running the
+    /// [evcxr](https://github.com/google/evcxr) kernel.
+    ///
+    /// Two representations are emitted: an html fragment that loads plotly.js from
+    /// `PLOTLY_JS_CDN`, and the plot as `application/vnd.plotly.v1+json` which is rendered natively
+    /// by JupyterLab.
+    pub fn notebook_display(&self) {
+        if let Err(e) = self.try_notebook_display() {
+            panic!("{}", e);
+        }
+    }
+
+    /// Same as `Plot::notebook_display`, but returns an `Error` instead of panicking.
+    pub fn try_notebook_display(&self) -> Result<(), Error> {
+        print!("{}", self.render_notebook_content()?);
+        Ok(())
+    }
+
+    /// Called by the evcxr kernel to display the `Plot` when it is the value of a cell; same as
+    /// `Plot::notebook_display`.
+    pub fn evcxr_display(&self) {
+        self.notebook_display();
+    }
+
+    fn render_notebook_content(&self) -> Result<String, Error> {
+        let plot_div_id = Plot::random_div_id();
+        let plot_data = self.render_plot_data();
+        let tmpl = NotebookPlotTemplate {
+            plot_data: plot_data.as_str(),
+            plot_div_id: plot_div_id.as_str(),
+            plotly_cdn: PLOTLY_JS_CDN,
+            animated: !self.frames.is_empty(),
+        };
+        let html = tmpl.render()?;
+
+        Ok(format!(
+            "EVCXR_BEGIN_CONTENT text/html\n{}\nEVCXR_END_CONTENT\n\
+             EVCXR_BEGIN_CONTENT application/vnd.plotly.v1+json\n{}\nEVCXR_END_CONTENT\n",
+            html,
+            self.render_orca_format()
+        ))
+    }
+
+    // The id is interpolated into both the `id` attribute of the `<div>` and a JavaScript string
+    // literal in the templates, so only characters that need no escaping in either are allowed.
+    fn is_valid_div_id(id: &str) -> bool {
+        let mut chars = id.chars();
+        match chars.next() {
+            Some(c) if c.is_ascii_alphabetic() => {
+                chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            }
+            _ => false,
+        }
+    }
+
+    fn random_div_id() -> String {
+        let id = rand::thread_rng()
+            .sample_iter(&rand::distributions::Alphanumeric)
+            .take(20)
+            .collect::<String>();
+        format!("plot-{}", id)
     }
 
     fn plotly_js_path() -> PathBuf {
-        let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         let templates = root.join("templates");
-        let plotly_path = templates.join(PLOTLY_JS);
-        plotly_path
+        templates.join(PLOTLY_JS)
     }
 
-    fn render(
-        &self,
-        export_image: bool,
-        image_type: &str,
-        image_width: usize,
-        image_height: usize,
-    ) -> String {
+    fn write_temp_html(rendered: &str, prefix: &str) -> Result<PathBuf, Error> {
+        let mut temp = env::temp_dir();
+
+        let mut plot_name = rand::thread_rng()
+            .sample_iter(&rand::distributions::Alphanumeric)
+            .take(22)
+            .collect::<String>();
+        plot_name.push_str(".html");
+        plot_name = format!("{}{}", prefix, plot_name);
+
+        temp.push(plot_name);
+        {
+            let mut file = File::create(&temp)?;
+            file.write_all(rendered.as_bytes())?;
+            file.flush()?;
+        }
+        Ok(temp)
+    }
+
+    fn render_plot_data(&self) -> String {
         let mut plot_data = String::new();
         for (idx, trace) in self.traces.iter().enumerate() {
             let s = trace.serialize();
             plot_data.push_str(format!("var trace_{} = {};\n", idx, s).as_str());
         }
-        plot_data.push_str("\n");
+        plot_data.push('\n');
         plot_data.push_str("var data = [");
         for idx in 0..self.traces.len() {
             if idx != self.traces.len() - 1 {
@@ -279,74 +770,142 @@ impl Plot {
             }
         };
         plot_data.push_str(layout_data.as_str());
+        plot_data.push_str(format!("var config = {};\n", self.render_configuration()).as_str());
+        if !self.frames.is_empty() {
+            plot_data.push_str(
+                format!(
+                    "var frames = {};\n",
+                    serde_json::to_string(&self.frames).unwrap()
+                )
+                .as_str(),
+            );
+            plot_data.push_str(
+                format!(
+                    "var animation_options = {};\n",
+                    serde_json::to_string(&self.animation_options).unwrap()
+                )
+                .as_str(),
+            );
+        }
+        plot_data
+    }
+
+    fn render_configuration(&self) -> String {
+        serde_json::to_string(&self.configuration).unwrap()
+    }
 
-        let plotly_js = PlotlyJs {}.render().unwrap();
+    fn render(
+        &self,
+        export_image: bool,
+        image_type: &str,
+        image_width: usize,
+        image_height: usize,
+    ) -> Result<String, Error> {
+        let plot_data = self.render_plot_data();
+
+        let plotly_script = match &self.include_plotly_js {
+            IncludePlotlyJs::Inline => format!(
+                r#"<script type="text/javascript">{}</script>"#,
+                PlotlyJs {}.render()?
+            ),
+            IncludePlotlyJs::Cdn(src) | IncludePlotlyJs::RelativePath(src) => {
+                format!(r#"<script src="{}"></script>"#, src)
+            }
+            IncludePlotlyJs::None => String::new(),
+        };
+        let mathjax_script = match &self.include_mathjax {
+            IncludeMathJax::Cdn(src) | IncludeMathJax::RelativePath(src) => {
+                format!(r#"<script src="{}"></script>"#, src)
+            }
+            IncludeMathJax::None => String::new(),
+        };
         let tmpl = PlotTemplate {
             plot_data: plot_data.as_str(),
-            plotly_javascript: plotly_js.as_str(),
+            plotly_script: plotly_script.as_str(),
+            mathjax_script: mathjax_script.as_str(),
             export_image,
             image_type,
             image_width,
             image_height,
+            animated: !self.frames.is_empty(),
         };
-        tmpl.render().unwrap()
+        Ok(tmpl.render()?)
     }
 
     fn render_orca_format(&self) -> String {
-        let mut plot_data: Vec<String> = Vec::new();
-        for trace in self.traces.iter() {
-            let s = trace.serialize();
-            plot_data.push(s);
-        }
-        let layout_data = match &self.layout {
-            Some(layout) => Trace::serialize(layout),
-            None => "{}".to_owned(),
-        };
+        let mut figure = self.to_json_value();
+        figure["config"] = serde_json::to_value(&self.configuration).unwrap();
+        figure.to_string()
+    }
 
-        let mut orca_data = String::new();
-        orca_data.push_str(r#"{"data": ["#);
+    fn to_json_value(&self) -> serde_json::Value {
+        let data = self
+            .traces
+            .iter()
+            .map(|trace| serde_json::from_str(&trace.serialize()).unwrap())
+            .collect::<Vec<serde_json::Value>>();
+        let layout = match &self.layout {
+            Some(layout) => serde_json::to_value(layout).unwrap(),
+            None => serde_json::json!({}),
+        };
+        let mut figure = serde_json::json!({ "data": data, "layout": layout });
+        if !self.frames.is_empty() {
+            figure["frames"] = serde_json::to_value(&self.frames).unwrap();
+        }
+        figure
+    }
 
-        for (index, data) in plot_data.iter().enumerate() {
-            if index < plot_data.len() - 1 {
-                orca_data.push_str(data);
-                orca_data.push_str(r#","#);
-            } else {
-                orca_data.push_str(data);
-                orca_data.push_str("]");
-            }
+    fn check_default_app_status(output: Output) -> Result<(), Error> {
+        if !output.status.success() {
+            return Err(Error::DefaultAppFailed {
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            });
         }
-        orca_data.push_str(format!(r#", "layout": {}"#, layout_data).as_str());
-        orca_data.push_str("}");
-        orca_data
+        Ok(())
     }
 
     #[cfg(target_os = "linux")]
-    fn show_with_default_app(temp_path: &str) {
-        Command::new("xdg-open")
-            .args(&[temp_path])
+    fn show_with_default_app(temp_path: &Path) -> Result<(), Error> {
+        let output = Command::new("xdg-open")
+            .arg(temp_path)
             .output()
-            .expect(DEFAULT_HTML_APP_NOT_FOUND);
+            .map_err(Error::DefaultAppNotFound)?;
+        Plot::check_default_app_status(output)
     }
 
     #[cfg(target_os = "macos")]
-    fn show_with_default_app(temp_path: &str) {
-        Command::new("open").args(&[temp_path]).output().expect(DEFAULT_HTML_APP_NOT_FOUND);
+    fn show_with_default_app(temp_path: &Path) -> Result<(), Error> {
+        let output = Command::new("open")
+            .arg(temp_path)
+            .output()
+            .map_err(Error::DefaultAppNotFound)?;
+        Plot::check_default_app_status(output)
     }
 
     #[cfg(target_os = "windows")]
-    fn show_with_default_app(temp_path: &str) {
-        Command::new("cmd")
+    fn show_with_default_app(temp_path: &Path) -> Result<(), Error> {
+        let output = Command::new("cmd")
             .arg("/C")
-            .arg(format!(r#"start {}"#, temp_path))
+            .arg(format!(r#"start {}"#, temp_path.display()))
             .output()
-            .expect(DEFAULT_HTML_APP_NOT_FOUND);
+            .map_err(Error::DefaultAppNotFound)?;
+        Plot::check_default_app_status(output)
+    }
+}
+
+impl Default for Plot {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::Scatter;
+    use crate::animation::FrameSettings;
+    use crate::common::Title;
+    use crate::layout::BarMode;
 
     fn create_test_plot() -> Plot {
         let trace1 = Scatter::new(vec![0, 1, 2], vec![6, 10, 2]).name("trace1");
@@ -355,6 +914,259 @@ mod tests {
         plot
     }
 
+    #[test]
+    fn test_try_to_html() {
+        let plot = create_test_plot();
+        let dst = PathBuf::from("example_try_to_html.html");
+        plot.try_to_html(&dst).unwrap();
+        assert!(dst.exists());
+        std::fs::remove_file(&dst).unwrap();
+        assert!(!dst.exists());
+    }
+
+    #[test]
+    fn test_write_html() {
+        let plot = create_test_plot();
+        let mut buffer: Vec<u8> = Vec::new();
+        plot.write_html(&mut buffer).unwrap();
+        let html = String::from_utf8(buffer).unwrap();
+        assert_eq!(html, plot.to_html_string());
+        assert!(html.contains(r#""name":"trace1""#));
+    }
+
+    #[test]
+    fn test_set_configuration() {
+        let mut plot = create_test_plot();
+        assert!(plot
+            .to_html_string()
+            .contains(r#"var config = {"responsive":true};"#));
+
+        plot.set_configuration(Configuration::new().static_plot(true).scroll_zoom(false));
+        let expected = r#"var config = {"staticPlot":true,"scrollZoom":false};"#;
+        assert!(plot.to_html_string().contains(expected));
+        assert!(plot.to_inline_html(None).contains(expected));
+        let figure: Value = serde_json::from_str(&plot.render_orca_format()).unwrap();
+        assert_eq!(
+            figure["config"],
+            serde_json::json!({"staticPlot": true, "scrollZoom": false})
+        );
+    }
+
+    #[test]
+    fn test_include_plotly_js() {
+        let mut plot = create_test_plot();
+        let inline = plot.to_html_string();
+        assert!(inline.len() > 1_000_000);
+
+        plot.set_include_plotly_js(IncludePlotlyJs::cdn());
+        let cdn = plot.to_html_string();
+        assert!(cdn.contains(r#"<script src="https://cdn.plot.ly/plotly-1.52.2.min.js"></script>"#));
+        assert!(cdn.len() < 10_000);
+
+        plot.set_include_plotly_js(IncludePlotlyJs::None);
+        plot.set_include_mathjax(IncludeMathJax::None);
+        let none = plot.to_html_string();
+        assert!(!none.contains("<script src="));
+    }
+
+    #[test]
+    fn test_to_inline_html() {
+        let plot = create_test_plot();
+        let html = plot.to_inline_html(Some("my-plot"));
+        assert!(html.starts_with(r#"<div id="my-plot""#));
+        assert!(html.contains("Plotly.newPlot('my-plot'"));
+        assert!(!html.contains("<html>"));
+    }
+
+    #[test]
+    fn test_try_to_inline_html_invalid_div_id() {
+        let plot = create_test_plot();
+        for id in &["", "1plot", "my plot", r#"a"><script>"#, "a');alert('x"] {
+            assert!(matches!(
+                plot.try_to_inline_html(Some(id)),
+                Err(Error::InvalidDivId(_))
+            ));
+        }
+        assert!(plot.try_to_inline_html(Some("plot_1-a")).is_ok());
+    }
+
+    #[test]
+    fn test_animation_frames() {
+        let mut plot = create_test_plot();
+        assert!(!plot.to_inline_html(None).contains("Plotly.addFrames"));
+        plot.add_frame(
+            Frame::new()
+                .name("end")
+                .add_trace(Scatter::new(vec![0, 1, 2], vec![2, 10, 6])),
+        );
+        plot.set_animation_options(
+            AnimationOptions::new().frame(FrameSettings::new().duration(500.0)),
+        );
+        let html = plot.to_inline_html(None);
+        assert!(html.contains("Plotly.addFrames(gd, frames)"));
+        assert!(html.contains(r#"var animation_options = {"frame":{"duration":500.0}};"#));
+
+        let json = plot.to_json();
+        let figure: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(figure["frames"][0]["name"], "end");
+        let round_trip: Value =
+            serde_json::from_str(&Plot::from_json(&json).unwrap().to_json()).unwrap();
+        assert_eq!(round_trip["frames"], figure["frames"]);
+    }
+
+    #[test]
+    fn test_notebook_content() {
+        let plot = create_test_plot();
+        let content = plot.render_notebook_content().unwrap();
+        assert!(content.starts_with("EVCXR_BEGIN_CONTENT text/html\n<div id="));
+        assert!(content.contains(&format!(
+            "EVCXR_END_CONTENT\nEVCXR_BEGIN_CONTENT application/vnd.plotly.v1+json\n{}\n",
+            plot.render_orca_format()
+        )));
+        assert!(content.ends_with("EVCXR_END_CONTENT\n"));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut plot = create_test_plot();
+        plot.set_layout(Layout::new().title(Title::new("title")));
+        let expected = serde_json::json!({
+            "data": [{"type": "scatter", "x": [0, 1, 2], "y": [6, 10, 2], "name": "trace1"}],
+            "layout": {"title": {"text": "title"}}
+        });
+        assert_eq!(
+            serde_json::from_str::<Value>(&plot.to_json()).unwrap(),
+            expected
+        );
+        assert_eq!(Plot::new().to_json(), r#"{"data":[],"layout":{}}"#);
+    }
+
+    #[test]
+    fn test_from_json_round_trip() {
+        let mut plot = Plot::new();
+        plot.add_trace(Scatter::new(vec![0, 1, 2], vec![6.0, 10.5, 2.0]).name("trace1"));
+        plot.add_trace(Bar::new(vec!["a", "b"], vec![1.5, 2.5]).name("trace2"));
+        plot.set_layout(
+            Layout::new()
+                .title(Title::new("title"))
+                .bar_mode(BarMode::Stack),
+        );
+        let json = plot.to_json();
+        assert_eq!(Plot::from_json(&json).unwrap().to_json(), json);
+    }
+
+    #[test]
+    fn test_from_json_python_figure() {
+        let json = r##"{
+            "data": [{"x": [1, 2], "y": [3, 4], "mode": "lines"},
+                     {"type": "histogram", "x": ["2020-01-01", "2020-01-02"]}],
+            "layout": {"template": {"layout": {"font": {"color": "#2a3f5f"}}}}
+        }"##;
+        let plot = Plot::from_json(json).unwrap();
+        let figure: Value = serde_json::from_str(&plot.to_json()).unwrap();
+        assert_eq!(figure["data"][0]["type"], "scatter");
+        assert_eq!(figure["data"][1]["x"][1], "2020-01-02");
+        assert_eq!(
+            figure["layout"]["template"]["layout"]["font"]["color"],
+            "#2a3f5f"
+        );
+    }
+
+    #[test]
+    fn test_from_json_python_export() {
+        let json = include_str!("../tests/fixtures/python_figure.json");
+        let plot = Plot::from_json(json).unwrap();
+        let mut expected: Value = serde_json::from_str(json).unwrap();
+        expected["layout"]["title"] = serde_json::json!({"text": "Stocks and animals"});
+        let actual: Value = serde_json::from_str(&plot.to_json()).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_from_json_invalid() {
+        assert!(matches!(Plot::from_json("{"), Err(Error::Json(_))));
+        let unknown_type = r#"{"data": [{"type": "not_a_trace"}]}"#;
+        assert!(matches!(Plot::from_json(unknown_type), Err(Error::Json(_))));
+        let invalid_attribute = r#"{"data": [{"type": "scatter", "mode": "nonsense"}]}"#;
+        assert!(matches!(
+            Plot::from_json(invalid_attribute),
+            Err(Error::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_json_unknown_attribute() {
+        let misspelled = [
+            r#"{"data": [{"type": "scatter", "fooo": 1}]}"#,
+            r#"{"data": [{"type": "bar", "marker": {"colour": "red"}}]}"#,
+            r#"{"layout": {"barmodee": "stack"}}"#,
+            r#"{"layout": {"xaxis": {"rangeslider": {"yaxis1": {}}}}}"#,
+            r#"{"layout": {"xaxis": {"rangeslider": {"foo": {}}}}}"#,
+            r#"{"layout": {"xaxis1": {}}}"#,
+            r#"{"layout": {"scene02": {}}}"#,
+            r#"{"layout": {"xaxis12": {"fooo": 1}}}"#,
+            r#"{"data": [], "layuot": {}}"#,
+        ];
+        for json in misspelled.iter() {
+            assert!(matches!(Plot::from_json(json), Err(Error::Json(_))));
+        }
+        let range_slider = r#"{"layout": {"xaxis": {"rangeslider": {"yaxis2": {}}}}}"#;
+        assert!(Plot::from_json(range_slider).is_ok());
+
+        let plot = create_test_plot();
+        let figure = Plot::from_json(&plot.render_orca_format()).unwrap();
+        assert_eq!(figure.render_orca_format(), plot.render_orca_format());
+    }
+
+    #[test]
+    fn test_any_trace_round_trip() {
+        let traces = vec![
+            r#"{"type":"scatter","x":["2020-01-01","2020-01-02"],"y":[1.0,2.0],"mode":"lines+markers"}"#,
+            r#"{"type":"scatter3d","x":[1,2],"y":[3,4],"z":[5,6],"surfaceaxis":2,"projection":{"z":{"show":true}},"scene":"scene2"}"#,
+            r#"{"type":"scatterpolar","theta":["a","b","c"],"r":[1,2,3],"fill":"toself","subplot":"polar2"}"#,
+            r#"{"type":"barpolar","theta":[0,90],"r":[1.5,2.5],"thetaunit":"degrees"}"#,
+            r#"{"type":"bar","x":["a","b"],"y":[1.5,2.5],"orientation":"v","xaxis":"x2","yaxis":"y2"}"#,
+            r#"{"type":"box","y":[1,2,3,4],"boxpoints":false,"boxmean":"sd"}"#,
+            r#"{"type":"candlestick","x":[1,2],"open":[1.0,2.0],"high":[3.0,4.0],"low":[0.5,1.5],"close":[2.0,3.0]}"#,
+            r#"{"type":"contour","z":[[1,2],[3,4]]}"#,
+            r#"{"type":"heatmap","z":[[1,2],[3,4]],"x":["a","b"],"y":["c","d"]}"#,
+            r#"{"type":"histogram","x":[1,2,2,3]}"#,
+            r#"{"type":"histogram2d","x":[1,2,2],"y":[3,4,4],"nbinsx":2,"histnorm":"percent"}"#,
+            r#"{"type":"histogram2dcontour","x":[1,2],"y":[3,4],"contours":{"coloring":"heatmap","showlines":false}}"#,
+            r#"{"type":"ohlc","x":[1,2],"open":[1.0,2.0],"high":[3.0,4.0],"low":[0.5,1.5],"close":[2.0,3.0]}"#,
+            r#"{"type":"pie","values":[1,2,3],"labels":["a","b","c"],"hole":0.4,"direction":"clockwise","domain":{"row":0,"column":1},"textinfo":"label+percent","hoverinfo":"skip"}"#,
+            r#"{"type":"sankey","node":{"label":["a","b"],"pad":15.0},"link":{"source":[0],"target":[1],"value":[2.0],"hoverinfo":"skip"},"arrangement":"snap","hoverinfo":"none"}"#,
+            r#"{"type":"sunburst","labels":["a","b"],"parents":["","a"],"values":[1.0,2.0],"branchvalues":"total","textinfo":"label+percent parent"}"#,
+            r#"{"type":"surface","z":[[1.0,2.0],[3.0,4.0]]}"#,
+            r#"{"type":"treemap","labels":["a","b"],"parents":["","a"],"tiling":{"packing":"slice-dice"},"pathbar":{"side":"bottom"},"hoverinfo":"current path+value"}"#,
+            r#"{"type":"violin","y":[1,2,3],"points":false,"side":"positive","box":{"visible":true},"meanline":{"visible":true}}"#,
+            r#"{"type":"waterfall","x":["a","b","c"],"y":[10,-4,6],"measure":["absolute","relative","total"],"connector":{"mode":"between"},"textinfo":"label+delta"}"#,
+        ];
+        for json in traces {
+            let trace: AnyTrace = serde_json::from_str(json).unwrap();
+            let expected: Value = serde_json::from_str(json).unwrap();
+            let actual: Value = serde_json::from_str(&Trace::serialize(&trace)).unwrap();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_any_trace_default_type() {
+        let trace: AnyTrace = serde_json::from_str(r#"{"x":[1],"y":[2.0]}"#).unwrap();
+        assert!(matches!(trace, AnyTrace::Scatter(_)));
+    }
+
+    #[test]
+    fn test_try_to_html_invalid_path() {
+        let plot = create_test_plot();
+        let dst = PathBuf::from("non_existent_directory").join("example.html");
+        match plot.try_to_html(&dst) {
+            Err(Error::Io(_)) => {}
+            other => panic!("expected an i/o error, got {:?}", other),
+        }
+        assert!(!dst.exists());
+    }
+
     #[test]
     #[cfg(feature = "orca")]
     fn test_to_png() {
@@ -362,7 +1174,7 @@ mod tests {
         let dst = PathBuf::from("example.png");
         plot.to_png(&dst, 1024, 680);
         assert!(dst.exists());
-        std::fs::remove_file(&dst);
+        std::fs::remove_file(&dst).unwrap();
         assert!(!dst.exists());
     }
 
@@ -373,7 +1185,7 @@ mod tests {
         let dst = PathBuf::from("example.jpeg");
         plot.to_jpeg(&dst, 1024, 680);
         assert!(dst.exists());
-        std::fs::remove_file(&dst);
+        std::fs::remove_file(&dst).unwrap();
         assert!(!dst.exists());
     }
 
@@ -384,7 +1196,7 @@ mod tests {
         let dst = PathBuf::from("example.webp");
         plot.to_webp(&dst, 1024, 680);
         assert!(dst.exists());
-        std::fs::remove_file(&dst);
+        std::fs::remove_file(&dst).unwrap();
         assert!(!dst.exists());
     }
 
@@ -395,7 +1207,7 @@ mod tests {
         let dst = PathBuf::from("example.svg");
         plot.to_svg(&dst, 1024, 680);
         assert!(dst.exists());
-        std::fs::remove_file(&dst);
+        std::fs::remove_file(&dst).unwrap();
         assert!(!dst.exists());
     }
 
@@ -406,7 +1218,7 @@ mod tests {
         let dst = PathBuf::from("example.pdf");
         plot.to_pdf(&dst, 1024, 680);
         assert!(dst.exists());
-        std::fs::remove_file(&dst);
+        std::fs::remove_file(&dst).unwrap();
         assert!(!dst.exists());
     }
 
@@ -417,7 +1229,7 @@ mod tests {
         let dst = PathBuf::from("example.eps");
         plot.to_eps(&dst, 1024, 680);
         assert!(dst.exists());
-        std::fs::remove_file(&dst);
+        std::fs::remove_file(&dst).unwrap();
         assert!(!dst.exists());
     }
 }
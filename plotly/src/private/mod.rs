@@ -1,5 +1,7 @@
 use crate::common::color::Color;
-use serde::{Serialize, Serializer};
+use serde::de::{self, DeserializeOwned};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
 
 pub fn owned_string_vector<S: AsRef<str>>(s: Vec<S>) -> Vec<String> {
     s.iter()
@@ -42,3 +44,114 @@ where
         serializer.serialize_str(&s)
     }
 }
+
+impl<'de, E> Deserialize<'de> for TruthyEnum<E>
+where
+    E: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<TruthyEnum<E>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let value = match value {
+            serde_json::Value::Bool(b) => serde_json::Value::String(b.to_string()),
+            v => v,
+        };
+        let e = E::deserialize(value).map_err(de::Error::custom)?;
+        Ok(TruthyEnum { e })
+    }
+}
+
+/// A plotly.js flaglist, such as `"label+percent"`, serialized from a list of enum flags.
+#[derive(Debug)]
+pub struct FlagList<F> {
+    pub flags: Vec<F>,
+}
+
+impl<F> Serialize for FlagList<F>
+where
+    F: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        let flags = self
+            .flags
+            .iter()
+            .map(|flag| match serde_json::to_value(flag) {
+                Ok(serde_json::Value::String(flag)) => Ok(flag),
+                _ => Err(serde::ser::Error::custom("flags must serialize as strings")),
+            })
+            .collect::<Result<Vec<String>, S::Error>>()?;
+        serializer.serialize_str(&flags.join("+"))
+    }
+}
+
+impl<'de, F> Deserialize<'de> for FlagList<F>
+where
+    F: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<FlagList<F>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let flags = String::deserialize(deserializer)?
+            .split('+')
+            .map(|flag| F::deserialize(serde_json::Value::from(flag)).map_err(de::Error::custom))
+            .collect::<Result<Vec<F>, D::Error>>()?;
+        Ok(FlagList { flags })
+    }
+}
+
+/// Returns whether `name` is one of the indexed attribute names plotly.js uses for subplots and
+/// their axes, e.g. `xaxis`, `xaxis2`, `xaxis3`, ... for the prefix `xaxis`.
+pub fn is_indexed_name(name: &str, prefix: &str) -> bool {
+    match name.strip_prefix(prefix) {
+        Some("") => true,
+        Some(index) => {
+            !index.starts_with('0') && index != "1" && index.chars().all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+/// Deserializes the flattened remainder of a struct into a map of indexed attributes with one of
+/// the given prefixes. As a flattened map accepts any attribute, `#[serde(deny_unknown_fields)]`
+/// cannot be used on such a struct; any other attribute is rejected here instead.
+pub fn deserialize_indexed_map<'de, D, V>(
+    deserializer: D,
+    prefixes: &[&str],
+) -> Result<BTreeMap<String, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: DeserializeOwned,
+{
+    deserialize_indexed_map_among(deserializer, prefixes, prefixes)
+}
+
+/// Like `deserialize_indexed_map`, for a struct with several flattened maps that each see the
+/// whole remainder: the attributes with one of `prefixes` are kept, those with one of `known` are
+/// left to the other maps, and any other attribute is rejected.
+pub fn deserialize_indexed_map_among<'de, D, V>(
+    deserializer: D,
+    prefixes: &[&str],
+    known: &[&str],
+) -> Result<BTreeMap<String, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    V: DeserializeOwned,
+{
+    let map = BTreeMap::<String, serde_json::Value>::deserialize(deserializer)?;
+    let mut indexed = BTreeMap::new();
+    for (name, value) in map {
+        if prefixes.iter().any(|prefix| is_indexed_name(&name, prefix)) {
+            let value = V::deserialize(value).map_err(de::Error::custom)?;
+            indexed.insert(name, value);
+        } else if !known.iter().any(|prefix| is_indexed_name(&name, prefix)) {
+            return Err(de::Error::custom(format!("unknown field `{}`", name)));
+        }
+    }
+    Ok(indexed)
+}
@@ -0,0 +1,403 @@
+//! Sankey diagram
+
+use crate::common::color::Color;
+use crate::common::{Dim, Domain, Font, Label, Line, Orientation, PlotType, Visible};
+use crate::private;
+use crate::Trace;
+use serde::{Deserialize, Serialize};
+
+/// The hover behaviour of a `Sankey` trace and of its nodes and links, which only accept these
+/// values rather than the flags of the other traces' `HoverInfo`.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum SankeyHoverInfo {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "skip")]
+    Skip,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Arrangement {
+    #[serde(rename = "snap")]
+    Snap,
+    #[serde(rename = "perpendicular")]
+    Perpendicular,
+    #[serde(rename = "freeform")]
+    Freeform,
+    #[serde(rename = "fixed")]
+    Fixed,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct SankeyNode {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pad: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thickness: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<SankeyHoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl SankeyNode {
+    pub fn new() -> SankeyNode {
+        SankeyNode {
+            label: None,
+            color: None,
+            pad: None,
+            thickness: None,
+            x: None,
+            y: None,
+            line: None,
+            hover_info: None,
+            hover_template: None,
+            hover_label: None,
+        }
+    }
+
+    pub fn label<S: AsRef<str>>(mut self, label: Vec<S>) -> SankeyNode {
+        let label = private::owned_string_vector(label);
+        self.label = Some(label);
+        self
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> SankeyNode {
+        self.color = Some(Dim::Scalar(color.to_color_string()));
+        self
+    }
+
+    pub fn color_array<C: Color>(mut self, color: Vec<C>) -> SankeyNode {
+        let color = private::to_color_array(color);
+        self.color = Some(Dim::Vector(color));
+        self
+    }
+
+    pub fn pad(mut self, pad: f64) -> SankeyNode {
+        self.pad = Some(pad);
+        self
+    }
+
+    pub fn thickness(mut self, thickness: f64) -> SankeyNode {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    pub fn x(mut self, x: Vec<f64>) -> SankeyNode {
+        self.x = Some(x);
+        self
+    }
+
+    pub fn y(mut self, y: Vec<f64>) -> SankeyNode {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn line(mut self, line: Line) -> SankeyNode {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn hover_info(mut self, hover_info: SankeyHoverInfo) -> SankeyNode {
+        self.hover_info = Some(hover_info);
+        self
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> SankeyNode {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        self
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> SankeyNode {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        self
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> SankeyNode {
+        self.hover_label = Some(hover_label);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct SankeyLink {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<Vec<usize>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<Vec<usize>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<SankeyHoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl SankeyLink {
+    pub fn new() -> SankeyLink {
+        SankeyLink {
+            source: None,
+            target: None,
+            value: None,
+            label: None,
+            color: None,
+            line: None,
+            hover_info: None,
+            hover_template: None,
+            hover_label: None,
+        }
+    }
+
+    pub fn source(mut self, source: Vec<usize>) -> SankeyLink {
+        self.source = Some(source);
+        self
+    }
+
+    pub fn target(mut self, target: Vec<usize>) -> SankeyLink {
+        self.target = Some(target);
+        self
+    }
+
+    pub fn value(mut self, value: Vec<f64>) -> SankeyLink {
+        self.value = Some(value);
+        self
+    }
+
+    pub fn label<S: AsRef<str>>(mut self, label: Vec<S>) -> SankeyLink {
+        let label = private::owned_string_vector(label);
+        self.label = Some(label);
+        self
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> SankeyLink {
+        self.color = Some(Dim::Scalar(color.to_color_string()));
+        self
+    }
+
+    pub fn color_array<C: Color>(mut self, color: Vec<C>) -> SankeyLink {
+        let color = private::to_color_array(color);
+        self.color = Some(Dim::Vector(color));
+        self
+    }
+
+    pub fn line(mut self, line: Line) -> SankeyLink {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn hover_info(mut self, hover_info: SankeyHoverInfo) -> SankeyLink {
+        self.hover_info = Some(hover_info);
+        self
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> SankeyLink {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        self
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> SankeyLink {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        self
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> SankeyLink {
+        self.hover_label = Some(hover_label);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Sankey {
+    r#type: PlotType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    node: Option<SankeyNode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    link: Option<SankeyLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    arrangement: Option<Arrangement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orientation: Option<Orientation>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "valueformat")]
+    value_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "valuesuffix")]
+    value_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<SankeyHoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+}
+
+impl Sankey {
+    pub fn new() -> Box<Sankey> {
+        Box::new(Sankey {
+            r#type: PlotType::Sankey,
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            ids: None,
+            node: None,
+            link: None,
+            arrangement: None,
+            orientation: None,
+            value_format: None,
+            value_suffix: None,
+            text_font: None,
+            hover_info: None,
+            hover_label: None,
+            domain: None,
+        })
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Sankey> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<Sankey> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Sankey> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Sankey> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Sankey> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn node(mut self, node: SankeyNode) -> Box<Sankey> {
+        self.node = Some(node);
+        Box::new(self)
+    }
+
+    pub fn link(mut self, link: SankeyLink) -> Box<Sankey> {
+        self.link = Some(link);
+        Box::new(self)
+    }
+
+    pub fn arrangement(mut self, arrangement: Arrangement) -> Box<Sankey> {
+        self.arrangement = Some(arrangement);
+        Box::new(self)
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Box<Sankey> {
+        self.orientation = Some(orientation);
+        Box::new(self)
+    }
+
+    pub fn value_format(mut self, value_format: &str) -> Box<Sankey> {
+        self.value_format = Some(value_format.to_owned());
+        Box::new(self)
+    }
+
+    pub fn value_suffix(mut self, value_suffix: &str) -> Box<Sankey> {
+        self.value_suffix = Some(value_suffix.to_owned());
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<Sankey> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: SankeyHoverInfo) -> Box<Sankey> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Sankey> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn domain(mut self, domain: Domain) -> Box<Sankey> {
+        self.domain = Some(domain);
+        Box::new(self)
+    }
+}
+
+impl Trace for Sankey {
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::color::NamedColor;
+
+    #[test]
+    fn test_serialize_sankey() {
+        let trace = Sankey::new()
+            .node(
+                SankeyNode::new()
+                    .label(vec!["a", "b", "c"])
+                    .color(NamedColor::Blue)
+                    .pad(15.0)
+                    .hover_info(SankeyHoverInfo::Skip),
+            )
+            .link(
+                SankeyLink::new()
+                    .source(vec![0, 0])
+                    .target(vec![1, 2])
+                    .value(vec![3.0, 5.0]),
+            )
+            .arrangement(Arrangement::Snap)
+            .orientation(Orientation::Horizontal)
+            .value_suffix("TWh");
+        let expected = r#"{"type":"sankey","node":{"label":["a","b","c"],"color":"blue","pad":15.0,"hoverinfo":"skip"},"link":{"source":[0,0],"target":[1,2],"value":[3.0,5.0]},"arrangement":"snap","orientation":"h","valuesuffix":"TWh"}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,463 @@
+//! Scatter3D plot
+
+use crate::common::color::Color;
+use crate::common::{
+    Calendar, Dim, ErrorData, Font, HoverInfo, Label, Line, Marker, Mode, PlotType, Position,
+    Visible,
+};
+use crate::private;
+use crate::Trace;
+use serde::de;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectionCoord {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scale: Option<f64>,
+}
+
+impl ProjectionCoord {
+    pub fn new() -> ProjectionCoord {
+        ProjectionCoord {
+            show: None,
+            opacity: None,
+            scale: None,
+        }
+    }
+
+    pub fn show(mut self, show: bool) -> ProjectionCoord {
+        self.show = Some(show);
+        self
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> ProjectionCoord {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    pub fn scale(mut self, scale: f64) -> ProjectionCoord {
+        self.scale = Some(scale);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Projection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<ProjectionCoord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<ProjectionCoord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    z: Option<ProjectionCoord>,
+}
+
+impl Projection {
+    pub fn new() -> Projection {
+        Projection {
+            x: None,
+            y: None,
+            z: None,
+        }
+    }
+
+    pub fn x(mut self, x: ProjectionCoord) -> Projection {
+        self.x = Some(x);
+        self
+    }
+
+    pub fn y(mut self, y: ProjectionCoord) -> Projection {
+        self.y = Some(y);
+        self
+    }
+
+    pub fn z(mut self, z: ProjectionCoord) -> Projection {
+        self.z = Some(z);
+        self
+    }
+}
+
+/// The axis perpendicular to which the area enclosed by the trace's points is filled with
+/// `surface_color`; `SurfaceAxis::None` disables the fill.
+#[derive(Debug)]
+pub enum SurfaceAxis {
+    None,
+    X,
+    Y,
+    Z,
+}
+
+impl Serialize for SurfaceAxis {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            SurfaceAxis::None => serializer.serialize_i8(-1),
+            SurfaceAxis::X => serializer.serialize_i8(0),
+            SurfaceAxis::Y => serializer.serialize_i8(1),
+            SurfaceAxis::Z => serializer.serialize_i8(2),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SurfaceAxis {
+    fn deserialize<D>(deserializer: D) -> Result<SurfaceAxis, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match i8::deserialize(deserializer)? {
+            -1 => Ok(SurfaceAxis::None),
+            0 => Ok(SurfaceAxis::X),
+            1 => Ok(SurfaceAxis::Y),
+            2 => Ok(SurfaceAxis::Z),
+            v => Err(de::Error::custom(format!("invalid surfaceaxis {}", v))),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Scatter3D<X, Y, Z>
+where
+    X: Serialize,
+    Y: Serialize,
+    Z: Serialize,
+{
+    r#type: PlotType,
+    x: Vec<X>,
+    y: Vec<Y>,
+    z: Vec<Z>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<Mode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textposition")]
+    text_position: Option<Dim<Position>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_x: Option<ErrorData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_y: Option<ErrorData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error_z: Option<ErrorData>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "connectgaps")]
+    connect_gaps: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    projection: Option<Projection>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "surfaceaxis")]
+    surface_axis: Option<SurfaceAxis>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "surfacecolor")]
+    surface_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "zcalendar")]
+    z_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scene: Option<String>,
+}
+
+impl<X, Y, Z> Scatter3D<X, Y, Z>
+where
+    X: Serialize,
+    Y: Serialize,
+    Z: Serialize,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>, z: Vec<Z>) -> Box<Scatter3D<X, Y, Z>> {
+        Box::new(Scatter3D {
+            r#type: PlotType::Scatter3D,
+            x,
+            y,
+            z,
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            mode: None,
+            ids: None,
+            text: None,
+            text_position: None,
+            text_template: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            marker: None,
+            line: None,
+            text_font: None,
+            error_x: None,
+            error_y: None,
+            error_z: None,
+            connect_gaps: None,
+            hover_label: None,
+            projection: None,
+            surface_axis: None,
+            surface_color: None,
+            x_calendar: None,
+            y_calendar: None,
+            z_calendar: None,
+            scene: None,
+        })
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Scatter3D<X, Y, Z>> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<Scatter3D<X, Y, Z>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Scatter3D<X, Y, Z>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Scatter3D<X, Y, Z>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Scatter3D<X, Y, Z>> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Scatter3D<X, Y, Z>> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Scatter3D<X, Y, Z>> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn mode(mut self, mode: Mode) -> Box<Scatter3D<X, Y, Z>> {
+        self.mode = Some(mode);
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Scatter3D<X, Y, Z>> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Scatter3D<X, Y, Z>> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Scatter3D<X, Y, Z>> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn text_position(mut self, text_position: Position) -> Box<Scatter3D<X, Y, Z>> {
+        self.text_position = Some(Dim::Scalar(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_position_array(mut self, text_position: Vec<Position>) -> Box<Scatter3D<X, Y, Z>> {
+        self.text_position = Some(Dim::Vector(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_template(mut self, text_template: &str) -> Box<Scatter3D<X, Y, Z>> {
+        self.text_template = Some(Dim::Scalar(text_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_template_array<S: AsRef<str>>(
+        mut self,
+        text_template: Vec<S>,
+    ) -> Box<Scatter3D<X, Y, Z>> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Scatter3D<X, Y, Z>> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(
+        mut self,
+        hover_text: Vec<S>,
+    ) -> Box<Scatter3D<X, Y, Z>> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Scatter3D<X, Y, Z>> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Scatter3D<X, Y, Z>> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(
+        mut self,
+        hover_template: Vec<S>,
+    ) -> Box<Scatter3D<X, Y, Z>> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Box<Scatter3D<X, Y, Z>> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn line(mut self, line: Line) -> Box<Scatter3D<X, Y, Z>> {
+        self.line = Some(line);
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<Scatter3D<X, Y, Z>> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    pub fn error_x(mut self, error_x: ErrorData) -> Box<Scatter3D<X, Y, Z>> {
+        self.error_x = Some(error_x);
+        Box::new(self)
+    }
+
+    pub fn error_y(mut self, error_y: ErrorData) -> Box<Scatter3D<X, Y, Z>> {
+        self.error_y = Some(error_y);
+        Box::new(self)
+    }
+
+    pub fn error_z(mut self, error_z: ErrorData) -> Box<Scatter3D<X, Y, Z>> {
+        self.error_z = Some(error_z);
+        Box::new(self)
+    }
+
+    pub fn connect_gaps(mut self, connect_gaps: bool) -> Box<Scatter3D<X, Y, Z>> {
+        self.connect_gaps = Some(connect_gaps);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Scatter3D<X, Y, Z>> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn projection(mut self, projection: Projection) -> Box<Scatter3D<X, Y, Z>> {
+        self.projection = Some(projection);
+        Box::new(self)
+    }
+
+    pub fn surface_axis(mut self, surface_axis: SurfaceAxis) -> Box<Scatter3D<X, Y, Z>> {
+        self.surface_axis = Some(surface_axis);
+        Box::new(self)
+    }
+
+    pub fn surface_color<C: Color>(mut self, surface_color: C) -> Box<Scatter3D<X, Y, Z>> {
+        self.surface_color = Some(surface_color.to_color_string());
+        Box::new(self)
+    }
+
+    pub fn x_calendar(mut self, x_calendar: Calendar) -> Box<Scatter3D<X, Y, Z>> {
+        self.x_calendar = Some(x_calendar);
+        Box::new(self)
+    }
+
+    pub fn y_calendar(mut self, y_calendar: Calendar) -> Box<Scatter3D<X, Y, Z>> {
+        self.y_calendar = Some(y_calendar);
+        Box::new(self)
+    }
+
+    pub fn z_calendar(mut self, z_calendar: Calendar) -> Box<Scatter3D<X, Y, Z>> {
+        self.z_calendar = Some(z_calendar);
+        Box::new(self)
+    }
+
+    pub fn scene(mut self, scene: &str) -> Box<Scatter3D<X, Y, Z>> {
+        self.scene = Some(scene.to_owned());
+        Box::new(self)
+    }
+}
+
+impl<X, Y, Z> Trace for Scatter3D<X, Y, Z>
+where
+    X: Serialize,
+    Y: Serialize,
+    Z: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::color::NamedColor;
+
+    #[test]
+    fn test_serialize_scatter3d() {
+        let trace = Scatter3D::new(vec![1, 2], vec![3, 4], vec![5, 6])
+            .mode(Mode::LinesMarkers)
+            .text_position(Position::TopCenter)
+            .connect_gaps(true)
+            .projection(Projection::new().z(ProjectionCoord::new().show(true).opacity(0.5)))
+            .surface_axis(SurfaceAxis::None)
+            .surface_color(NamedColor::Red)
+            .z_calendar(Calendar::Gregorian)
+            .scene("scene2");
+        let expected = r#"{"type":"scatter3d","x":[1,2],"y":[3,4],"z":[5,6],"mode":"lines+markers","textposition":"top center","connectgaps":true,"projection":{"z":{"show":true,"opacity":0.5}},"surfaceaxis":-1,"surfacecolor":"red","zcalendar":"gregorian","scene":"scene2"}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,323 @@
+//! Scatter plot in polar coordinates
+
+use crate::common::color::Color;
+use crate::common::{
+    Dim, Fill, Font, Label, Line, Marker, Mode, PlotType, PolarHoverInfo, Position, ThetaUnit,
+    Visible,
+};
+use crate::private;
+use crate::Trace;
+use serde::{Deserialize, Serialize};
+
+/// Flags for `ScatterPolar::hover_on`, e.g. `vec![HoverOn::Points, HoverOn::Fills]`.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum HoverOn {
+    #[serde(rename = "points")]
+    Points,
+    #[serde(rename = "fills")]
+    Fills,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ScatterPolar<Theta, R>
+where
+    Theta: Serialize,
+    R: Serialize,
+{
+    r#type: PlotType,
+    theta: Vec<Theta>,
+    r: Vec<R>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<Mode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "thetaunit")]
+    theta_unit: Option<ThetaUnit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textposition")]
+    text_position: Option<Dim<Position>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<private::FlagList<PolarHoverInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "connectgaps")]
+    connect_gaps: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "cliponaxis")]
+    clip_on_axis: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fill: Option<Fill>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
+    fill_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoveron")]
+    hover_on: Option<private::FlagList<HoverOn>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subplot: Option<String>,
+}
+
+impl<Theta, R> ScatterPolar<Theta, R>
+where
+    Theta: Serialize,
+    R: Serialize,
+{
+    pub fn new(theta: Vec<Theta>, r: Vec<R>) -> Box<ScatterPolar<Theta, R>> {
+        Box::new(ScatterPolar {
+            r#type: PlotType::ScatterPolar,
+            theta,
+            r,
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            mode: None,
+            ids: None,
+            theta_unit: None,
+            text: None,
+            text_position: None,
+            text_template: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            line: None,
+            connect_gaps: None,
+            marker: None,
+            clip_on_axis: None,
+            fill: None,
+            fill_color: None,
+            text_font: None,
+            hover_label: None,
+            hover_on: None,
+            subplot: None,
+        })
+    }
+
+    pub fn name(mut self, name: &str) -> Box<ScatterPolar<Theta, R>> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<ScatterPolar<Theta, R>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<ScatterPolar<Theta, R>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<ScatterPolar<Theta, R>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<ScatterPolar<Theta, R>> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<ScatterPolar<Theta, R>> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<ScatterPolar<Theta, R>> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn mode(mut self, mode: Mode) -> Box<ScatterPolar<Theta, R>> {
+        self.mode = Some(mode);
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<ScatterPolar<Theta, R>> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn theta_unit(mut self, theta_unit: ThetaUnit) -> Box<ScatterPolar<Theta, R>> {
+        self.theta_unit = Some(theta_unit);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<ScatterPolar<Theta, R>> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<ScatterPolar<Theta, R>> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn text_position(mut self, text_position: Position) -> Box<ScatterPolar<Theta, R>> {
+        self.text_position = Some(Dim::Scalar(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_position_array(
+        mut self,
+        text_position: Vec<Position>,
+    ) -> Box<ScatterPolar<Theta, R>> {
+        self.text_position = Some(Dim::Vector(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_template(mut self, text_template: &str) -> Box<ScatterPolar<Theta, R>> {
+        self.text_template = Some(Dim::Scalar(text_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_template_array<S: AsRef<str>>(
+        mut self,
+        text_template: Vec<S>,
+    ) -> Box<ScatterPolar<Theta, R>> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<ScatterPolar<Theta, R>> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(
+        mut self,
+        hover_text: Vec<S>,
+    ) -> Box<ScatterPolar<Theta, R>> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: Vec<PolarHoverInfo>) -> Box<ScatterPolar<Theta, R>> {
+        self.hover_info = Some(private::FlagList { flags: hover_info });
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<ScatterPolar<Theta, R>> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(
+        mut self,
+        hover_template: Vec<S>,
+    ) -> Box<ScatterPolar<Theta, R>> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn line(mut self, line: Line) -> Box<ScatterPolar<Theta, R>> {
+        self.line = Some(line);
+        Box::new(self)
+    }
+
+    pub fn connect_gaps(mut self, connect_gaps: bool) -> Box<ScatterPolar<Theta, R>> {
+        self.connect_gaps = Some(connect_gaps);
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Box<ScatterPolar<Theta, R>> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn clip_on_axis(mut self, clip_on_axis: bool) -> Box<ScatterPolar<Theta, R>> {
+        self.clip_on_axis = Some(clip_on_axis);
+        Box::new(self)
+    }
+
+    pub fn fill(mut self, fill: Fill) -> Box<ScatterPolar<Theta, R>> {
+        self.fill = Some(fill);
+        Box::new(self)
+    }
+
+    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Box<ScatterPolar<Theta, R>> {
+        self.fill_color = Some(fill_color.to_color_string());
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<ScatterPolar<Theta, R>> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<ScatterPolar<Theta, R>> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_on(mut self, hover_on: Vec<HoverOn>) -> Box<ScatterPolar<Theta, R>> {
+        self.hover_on = Some(private::FlagList { flags: hover_on });
+        Box::new(self)
+    }
+
+    pub fn subplot(mut self, subplot: &str) -> Box<ScatterPolar<Theta, R>> {
+        self.subplot = Some(subplot.to_owned());
+        Box::new(self)
+    }
+}
+
+impl<Theta, R> Trace for ScatterPolar<Theta, R>
+where
+    Theta: Serialize,
+    R: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_scatter_polar() {
+        let trace = ScatterPolar::new(vec![0, 90, 180], vec![1.0, 2.0, 1.5])
+            .mode(Mode::LinesMarkers)
+            .theta_unit(ThetaUnit::Degrees)
+            .hover_info(vec![PolarHoverInfo::R, PolarHoverInfo::Theta])
+            .fill(Fill::ToSelf)
+            .hover_on(vec![HoverOn::Points, HoverOn::Fills])
+            .subplot("polar2");
+        let expected = r#"{"type":"scatterpolar","theta":[0,90,180],"r":[1.0,2.0,1.5],"mode":"lines+markers","thetaunit":"degrees","hoverinfo":"r+theta","fill":"toself","hoveron":"points+fills","subplot":"polar2"}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
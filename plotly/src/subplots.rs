@@ -0,0 +1,640 @@
+//! Grids of subplots in the style of Python Plotly's `make_subplots`.
+
+use crate::common::{Anchor, AxisReference, Domain, Font, Side};
+use crate::layout::{Annotation, Axis, GridDomain, LayoutGrid, LayoutPolar, LayoutScene, RowOrder};
+use crate::private;
+use crate::{Error, Layout, Plot, Trace};
+use serde_json::Value;
+
+/// The kind of subplot drawn in a cell of a `Subplots` grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SubplotType {
+    /// A 2D cartesian subplot with its own x and y axes.
+    XY,
+    /// A 3D scene, for traces such as `Surface` and `Scatter3D`.
+    Scene,
+    /// A polar subplot, for `ScatterPolar` and `BarPolar` traces.
+    Polar,
+    /// A bare domain, for traces such as `Pie`, `Sunburst` and `Sankey` that position themselves
+    /// with a `domain`.
+    Domain,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubplotSpec {
+    subplot_type: SubplotType,
+    secondary_y: bool,
+}
+
+impl SubplotSpec {
+    pub fn new(subplot_type: SubplotType) -> SubplotSpec {
+        SubplotSpec {
+            subplot_type,
+            secondary_y: false,
+        }
+    }
+
+    /// Add a secondary y axis, overlaying the primary one on the right hand side. Only applies to
+    /// `SubplotType::XY` cells.
+    pub fn secondary_y(mut self, secondary_y: bool) -> SubplotSpec {
+        self.secondary_y = secondary_y;
+        self
+    }
+}
+
+/// Builder for a `Plot` laid out as a grid of subplots.
+///
+/// Rows and columns are numbered from 1, top to bottom and left to right, as in Python Plotly.
+/// Traces are placed in a cell with `Plot::add_trace_at`, which points them at the axes, scene,
+/// polar subplot or domain of that cell.
+///
+/// The cells are laid out by a `LayoutGrid`, so plotly.js sizes them. As its cells all have the
+/// same size, setting `row_heights` or `column_widths` gives the subplots explicit domains instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// use plotly::subplots::Subplots;
+/// use plotly::{Bar, Scatter};
+///
+/// let mut plot = Subplots::new(2, 1).shared_xaxes(true).build();
+/// plot.add_trace_at(Scatter::new(vec![1, 2, 3], vec![4, 5, 6]), 1, 1);
+/// plot.add_trace_at(Bar::new(vec![1, 2, 3], vec![2, 1, 3]), 2, 1);
+/// plot.show();
+/// ```
+#[derive(Debug)]
+pub struct Subplots {
+    rows: usize,
+    cols: usize,
+    shared_xaxes: bool,
+    shared_yaxes: bool,
+    row_heights: Option<Vec<f64>>,
+    column_widths: Option<Vec<f64>>,
+    horizontal_spacing: Option<f64>,
+    vertical_spacing: Option<f64>,
+    specs: Option<Vec<Vec<SubplotSpec>>>,
+    subplot_titles: Option<Vec<String>>,
+    layout: Option<Layout>,
+}
+
+impl Subplots {
+    pub fn new(rows: usize, cols: usize) -> Subplots {
+        assert!(
+            rows > 0 && cols > 0,
+            "a subplot grid needs at least one row and column"
+        );
+        Subplots {
+            rows,
+            cols,
+            shared_xaxes: false,
+            shared_yaxes: false,
+            row_heights: None,
+            column_widths: None,
+            horizontal_spacing: None,
+            vertical_spacing: None,
+            specs: None,
+            subplot_titles: None,
+            layout: None,
+        }
+    }
+
+    /// Link the x axes of the subplots in each column, only showing tick labels on the bottom one.
+    pub fn shared_xaxes(mut self, shared_xaxes: bool) -> Subplots {
+        self.shared_xaxes = shared_xaxes;
+        self
+    }
+
+    /// Link the y axes of the subplots in each row, only showing tick labels on the left one.
+    pub fn shared_yaxes(mut self, shared_yaxes: bool) -> Subplots {
+        self.shared_yaxes = shared_yaxes;
+        self
+    }
+
+    /// Relative heights of the rows, from top to bottom. Defaults to equal heights.
+    pub fn row_heights(mut self, row_heights: Vec<f64>) -> Subplots {
+        assert_eq!(
+            row_heights.len(),
+            self.rows,
+            "row_heights needs one entry per row"
+        );
+        self.row_heights = Some(row_heights);
+        self
+    }
+
+    /// Relative widths of the columns, from left to right. Defaults to equal widths.
+    pub fn column_widths(mut self, column_widths: Vec<f64>) -> Subplots {
+        assert_eq!(
+            column_widths.len(),
+            self.cols,
+            "column_widths needs one entry per column"
+        );
+        self.column_widths = Some(column_widths);
+        self
+    }
+
+    /// Space between columns as a fraction of the width of a cell. Defaults to 0.2.
+    pub fn horizontal_spacing(mut self, horizontal_spacing: f64) -> Subplots {
+        self.horizontal_spacing = Some(horizontal_spacing);
+        self
+    }
+
+    /// Space between rows as a fraction of the height of a cell. Defaults to 0.3.
+    pub fn vertical_spacing(mut self, vertical_spacing: f64) -> Subplots {
+        self.vertical_spacing = Some(vertical_spacing);
+        self
+    }
+
+    /// The kind of subplot in each cell, given row by row. Defaults to `SubplotType::XY` cells.
+    pub fn specs(mut self, specs: Vec<Vec<SubplotSpec>>) -> Subplots {
+        assert!(
+            specs.len() == self.rows && specs.iter().all(|row| row.len() == self.cols),
+            "specs needs one entry per cell"
+        );
+        self.specs = Some(specs);
+        self
+    }
+
+    /// Titles shown above the subplots, given row by row. Cells without a title may be skipped with
+    /// an empty string, and any cells after the last title are left untitled.
+    pub fn subplot_titles<S: AsRef<str>>(mut self, subplot_titles: Vec<S>) -> Subplots {
+        self.subplot_titles = Some(private::owned_string_vector(subplot_titles));
+        self
+    }
+
+    /// The `Layout` the grid, subplot axes, scenes and polar subplots are added to. Its grid and any
+    /// of those already set on it for the same subplot are replaced.
+    pub fn layout(mut self, layout: Layout) -> Subplots {
+        self.layout = Some(layout);
+        self
+    }
+
+    /// Build an empty `Plot` with the subplot grid as its layout.
+    ///
+    /// Replacing the layout afterwards with `Plot::set_layout` drops the subplot axes, so set any
+    /// other layout options with `Subplots::layout` instead.
+    pub fn build(mut self) -> Plot {
+        let x_gap = self.horizontal_spacing.unwrap_or(0.2);
+        let y_gap = self.vertical_spacing.unwrap_or(0.3);
+        let column_domains = Subplots::domains(self.column_widths.as_deref(), self.cols, x_gap);
+        let mut row_domains = Subplots::domains(self.row_heights.as_deref(), self.rows, y_gap);
+        // Rows are numbered from the top, but the y domain grows upwards.
+        for domain in row_domains.iter_mut() {
+            *domain = [1.0 - domain[1], 1.0 - domain[0]];
+        }
+
+        let mut x_count = 0;
+        let mut y_count = 0;
+        let mut scene_count = 0;
+        let mut polar_count = 0;
+        let mut cells = Vec::with_capacity(self.rows);
+        for (row, row_domain) in row_domains.iter().enumerate() {
+            let mut row_cells = Vec::with_capacity(self.cols);
+            for (col, column_domain) in column_domains.iter().enumerate() {
+                let spec = self.spec(row, col);
+                let cell = match spec.subplot_type {
+                    SubplotType::XY => {
+                        x_count += 1;
+                        y_count += 1;
+                        let y = y_count;
+                        let secondary_y = if spec.secondary_y {
+                            y_count += 1;
+                            Some(y_count)
+                        } else {
+                            None
+                        };
+                        Cell::XY {
+                            x: x_count,
+                            y,
+                            secondary_y,
+                        }
+                    }
+                    SubplotType::Scene => {
+                        scene_count += 1;
+                        Cell::Scene(scene_count)
+                    }
+                    SubplotType::Polar => {
+                        polar_count += 1;
+                        Cell::Polar(polar_count)
+                    }
+                    SubplotType::Domain => Cell::Domain,
+                };
+                row_cells.push(CellDomain {
+                    cell,
+                    row,
+                    col,
+                    x: *column_domain,
+                    y: *row_domain,
+                });
+            }
+            cells.push(row_cells);
+        }
+
+        let grid = LayoutGrid::new()
+            .rows(self.rows)
+            .columns(self.cols)
+            .row_order(RowOrder::TopToBottom)
+            .sub_plots(
+                cells
+                    .iter()
+                    .map(|row_cells| row_cells.iter().map(|cell| cell.cell.xy_id()).collect())
+                    .collect(),
+            )
+            .x_gap(x_gap)
+            .y_gap(y_gap)
+            // The subplot titles are placed by the same arithmetic, which assumes the whole plot.
+            .domain(GridDomain::new().x(vec![0.0, 1.0]).y(vec![0.0, 1.0]));
+        let layout = self.layout.take().unwrap_or_default().grid(grid);
+        let mut layout = self.layout_for(&cells, layout);
+        if let Some(subplot_titles) = &self.subplot_titles {
+            for (title, cell) in subplot_titles.iter().zip(cells.iter().flatten()) {
+                if !title.is_empty() {
+                    layout = layout.add_annotation(Subplots::title_annotation(title, cell));
+                }
+            }
+        }
+        let mut plot = Plot::new();
+        plot.set_layout(layout);
+        plot.set_subplot_grid(SubplotGrid {
+            cells,
+            explicit_domains: self.explicit_domains(),
+        });
+        plot
+    }
+
+    fn explicit_domains(&self) -> bool {
+        self.row_heights.is_some() || self.column_widths.is_some()
+    }
+
+    fn spec(&self, row: usize, col: usize) -> SubplotSpec {
+        match &self.specs {
+            Some(specs) => specs[row][col].clone(),
+            None => SubplotSpec::new(SubplotType::XY),
+        }
+    }
+
+    /// The extent of each row or column of the grid, split as plotly.js splits a grid: cells are
+    /// separated by `gap` times the size of an average cell, and share the rest by `sizes`.
+    fn domains(sizes: Option<&[f64]>, n: usize, gap: f64) -> Vec<[f64; 2]> {
+        let sizes = match sizes {
+            Some(sizes) => sizes.to_vec(),
+            None => vec![1.0; n],
+        };
+        let total: f64 = sizes.iter().sum();
+        let step = 1.0 / (n as f64 - gap);
+        let mut start = 0.0;
+        sizes
+            .iter()
+            .map(|size| {
+                let end = start + step * (1.0 - gap) * n as f64 * size / total;
+                let domain = [start, end.min(1.0)];
+                start = end + step * gap;
+                domain
+            })
+            .collect()
+    }
+
+    fn title_annotation(title: &str, cell: &CellDomain) -> Annotation {
+        Annotation::new()
+            .text(title)
+            .x_ref(AxisReference::Paper)
+            .y_ref(AxisReference::Paper)
+            .x((cell.x[0] + cell.x[1]) / 2.0)
+            .y(cell.y[1])
+            .x_anchor(Anchor::Center)
+            .y_anchor(Anchor::Bottom)
+            .show_arrow(false)
+            .font(Font::new().size(16))
+    }
+
+    fn layout_for(&self, cells: &[Vec<CellDomain>], mut layout: Layout) -> Layout {
+        let explicit_domains = self.explicit_domains();
+        for (row, row_cells) in cells.iter().enumerate() {
+            for (col, cell) in row_cells.iter().enumerate() {
+                layout = match cell.cell {
+                    Cell::XY { x, y, secondary_y } => {
+                        let mut x_axis = Axis::new().anchor(&subplot_id("y", y));
+                        let mut y_axis = Axis::new().anchor(&subplot_id("x", x));
+                        if explicit_domains {
+                            x_axis = x_axis.domain(cell.x.to_vec());
+                            y_axis = y_axis.domain(cell.y.to_vec());
+                        }
+                        if self.shared_xaxes {
+                            if let Some(top) =
+                                Subplots::first_xy(cells, (0..self.rows).map(|r| (r, col)))
+                            {
+                                if top.0 != x {
+                                    x_axis = x_axis.matches(&subplot_id("x", top.0));
+                                }
+                            }
+                            if (row + 1..self.rows).any(|r| cells[r][col].cell.is_xy()) {
+                                x_axis = x_axis.show_tick_labels(false);
+                            }
+                        }
+                        if self.shared_yaxes {
+                            if let Some(left) =
+                                Subplots::first_xy(cells, (0..self.cols).map(|c| (row, c)))
+                            {
+                                if left.1 != y {
+                                    y_axis = y_axis.matches(&subplot_id("y", left.1));
+                                }
+                            }
+                            if (0..col).any(|c| cells[row][c].cell.is_xy()) {
+                                y_axis = y_axis.show_tick_labels(false);
+                            }
+                        }
+                        layout = layout.x_axis_n(x, x_axis);
+                        layout = layout.y_axis_n(y, y_axis);
+                        match secondary_y {
+                            Some(secondary_y) => layout.y_axis_n(
+                                secondary_y,
+                                Axis::new()
+                                    .anchor(&subplot_id("x", x))
+                                    .overlaying(&subplot_id("y", y))
+                                    .side(Side::Right),
+                            ),
+                            None => layout,
+                        }
+                    }
+                    Cell::Scene(scene) => layout.scene_n(
+                        scene,
+                        LayoutScene::new().domain(cell.domain(explicit_domains)),
+                    ),
+                    Cell::Polar(polar) => layout.polar_n(
+                        polar,
+                        LayoutPolar::new().domain(cell.domain(explicit_domains)),
+                    ),
+                    Cell::Domain => layout,
+                };
+            }
+        }
+        layout
+    }
+
+    fn first_xy<I>(cells: &[Vec<CellDomain>], positions: I) -> Option<(usize, usize)>
+    where
+        I: Iterator<Item = (usize, usize)>,
+    {
+        positions
+            .filter_map(|(r, c)| match cells[r][c].cell {
+                Cell::XY { x, y, .. } => Some((x, y)),
+                _ => None,
+            })
+            .next()
+    }
+}
+
+/// The subplot each cell of a grid built by `Subplots` refers to, used by `Plot::add_trace_at` to
+/// place traces.
+#[derive(Debug)]
+pub(crate) struct SubplotGrid {
+    cells: Vec<Vec<CellDomain>>,
+    explicit_domains: bool,
+}
+
+#[derive(Debug)]
+struct CellDomain {
+    cell: Cell,
+    row: usize,
+    col: usize,
+    x: [f64; 2],
+    y: [f64; 2],
+}
+
+impl CellDomain {
+    /// The domain of a scene, polar subplot or domain trace in this cell: its cell of the layout
+    /// grid, or its extent when the subplots have explicit domains.
+    fn domain(&self, explicit_domains: bool) -> Domain {
+        if explicit_domains {
+            Domain::new().x(self.x.to_vec()).y(self.y.to_vec())
+        } else {
+            Domain::new().row(self.row).column(self.col)
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Cell {
+    XY {
+        x: usize,
+        y: usize,
+        secondary_y: Option<usize>,
+    },
+    Scene(usize),
+    Polar(usize),
+    Domain,
+}
+
+impl Cell {
+    fn is_xy(&self) -> bool {
+        matches!(self, Cell::XY { .. })
+    }
+
+    /// The id of the cartesian subplot in the cell for `LayoutGrid::sub_plots`, e.g. "x2y3".
+    fn xy_id(&self) -> String {
+        match self {
+            Cell::XY { x, y, .. } => format!("{}{}", subplot_id("x", *x), subplot_id("y", *y)),
+            _ => String::new(),
+        }
+    }
+}
+
+impl SubplotGrid {
+    /// Point `trace` at the subplot in the given cell, or at the secondary y axis of that cell.
+    pub(crate) fn place(
+        &self,
+        trace: &dyn Trace,
+        row: usize,
+        col: usize,
+        secondary_y: bool,
+    ) -> Result<Box<dyn Trace>, Error> {
+        if row < 1 || row > self.cells.len() || col < 1 || col > self.cells[0].len() {
+            return Err(Error::InvalidSubplot(format!(
+                "({}, {}) is outside the {}x{} grid",
+                row,
+                col,
+                self.cells.len(),
+                self.cells[0].len()
+            )));
+        }
+        let cell = &self.cells[row - 1][col - 1];
+        let mut trace: Value = serde_json::from_str(&trace.serialize()).unwrap();
+        let fields = trace.as_object_mut().unwrap();
+        match cell.cell {
+            Cell::XY {
+                x,
+                y,
+                secondary_y: secondary,
+            } => {
+                let y = match (secondary_y, secondary) {
+                    (false, _) => y,
+                    (true, Some(secondary)) => secondary,
+                    (true, None) => {
+                        return Err(Error::InvalidSubplot(format!(
+                            "({}, {}) has no secondary y axis",
+                            row, col
+                        )))
+                    }
+                };
+                fields.insert("xaxis".to_owned(), Value::from(subplot_id("x", x)));
+                fields.insert("yaxis".to_owned(), Value::from(subplot_id("y", y)));
+            }
+            Cell::Scene(scene) => {
+                fields.insert("scene".to_owned(), Value::from(subplot_id("scene", scene)));
+            }
+            Cell::Polar(polar) => {
+                fields.insert(
+                    "subplot".to_owned(),
+                    Value::from(subplot_id("polar", polar)),
+                );
+            }
+            Cell::Domain => {
+                let domain = serde_json::to_value(cell.domain(self.explicit_domains)).unwrap();
+                fields.insert("domain".to_owned(), domain);
+            }
+        }
+        Ok(Box::new(PlacedTrace { trace }))
+    }
+}
+
+/// A trace pointed at a subplot, kept as JSON as any trace type may be placed.
+struct PlacedTrace {
+    trace: Value,
+}
+
+impl Trace for PlacedTrace {
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self.trace).unwrap()
+    }
+}
+
+fn subplot_id(subplot: &str, index: usize) -> String {
+    if index == 1 {
+        subplot.to_owned()
+    } else {
+        format!("{}{}", subplot, index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Pie, Scatter};
+
+    #[test]
+    fn test_subplot_domains() {
+        let domains = Subplots::domains(Some(&[1.0, 3.0]), 2, 0.0);
+        assert_eq!(domains, vec![[0.0, 0.25], [0.25, 1.0]]);
+        let domains = Subplots::domains(None, 1, 0.2);
+        assert_eq!(domains, vec![[0.0, 1.0]]);
+    }
+
+    #[test]
+    fn test_add_trace_at() {
+        let mut plot = Subplots::new(2, 2)
+            .shared_xaxes(true)
+            .specs(vec![
+                vec![
+                    SubplotSpec::new(SubplotType::XY),
+                    SubplotSpec::new(SubplotType::XY).secondary_y(true),
+                ],
+                vec![
+                    SubplotSpec::new(SubplotType::XY),
+                    SubplotSpec::new(SubplotType::Domain),
+                ],
+            ])
+            .horizontal_spacing(0.0)
+            .vertical_spacing(0.0)
+            .subplot_titles(vec!["", "B"])
+            .build();
+        plot.add_trace_at(Scatter::new(vec![1], vec![1]), 2, 1);
+        plot.add_secondary_y_trace_at(Scatter::new(vec![1], vec![1]), 1, 2);
+        plot.add_trace_at(Pie::new(vec![1]), 2, 2);
+        let json: Value = serde_json::from_str(&plot.to_json()).unwrap();
+
+        assert_eq!(json["data"][0]["xaxis"], "x3");
+        assert_eq!(json["data"][0]["yaxis"], "y4");
+        assert_eq!(json["data"][1]["xaxis"], "x2");
+        assert_eq!(json["data"][1]["yaxis"], "y3");
+        assert_eq!(
+            json["data"][2]["domain"],
+            serde_json::json!({"row": 1, "column": 1})
+        );
+
+        let layout = &json["layout"];
+        assert_eq!(
+            layout["grid"],
+            serde_json::json!({
+                "rows": 2,
+                "roworder": "top to bottom",
+                "columns": 2,
+                "subplots": [["xy", "x2y2"], ["x3y4", ""]],
+                "xgap": 0.0,
+                "ygap": 0.0,
+                "domain": {"x": [0.0, 1.0], "y": [0.0, 1.0]}
+            })
+        );
+        assert!(layout["xaxis"].get("domain").is_none());
+        assert_eq!(layout["xaxis"]["showticklabels"], false);
+        assert_eq!(layout["xaxis3"]["matches"], "x");
+        assert_eq!(layout["yaxis3"]["overlaying"], "y2");
+        assert_eq!(layout["yaxis3"]["side"], "right");
+        assert_eq!(layout["annotations"].as_array().unwrap().len(), 1);
+        assert_eq!(layout["annotations"][0]["text"], "B");
+        assert_eq!(layout["annotations"][0]["x"], 0.75);
+        assert_eq!(layout["annotations"][0]["y"], 1.0);
+    }
+
+    #[test]
+    fn test_try_add_trace_at_invalid_subplot() {
+        let mut plot = Subplots::new(1, 2).build();
+        assert!(matches!(
+            plot.try_add_trace_at(Scatter::new(vec![1], vec![1]), 2, 1),
+            Err(Error::InvalidSubplot(_))
+        ));
+        assert!(matches!(
+            plot.try_add_secondary_y_trace_at(Scatter::new(vec![1], vec![1]), 1, 2),
+            Err(Error::InvalidSubplot(_))
+        ));
+        assert!(plot
+            .try_add_trace_at(Scatter::new(vec![1], vec![1]), 1, 2)
+            .is_ok());
+        assert!(matches!(
+            Plot::new().try_add_trace_at(Scatter::new(vec![1], vec![1]), 1, 1),
+            Err(Error::InvalidSubplot(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid subplot: (3, 1) is outside the 1x2 grid")]
+    fn test_add_trace_at_outside_grid() {
+        let mut plot = Subplots::new(1, 2).build();
+        plot.add_trace_at(Scatter::new(vec![1], vec![1]), 3, 1);
+    }
+
+    #[test]
+    fn test_large_grid_with_secondary_y() {
+        let specs = vec![vec![SubplotSpec::new(SubplotType::XY).secondary_y(true); 4]; 4];
+        let mut plot = Subplots::new(4, 4).specs(specs.clone()).build();
+        plot.add_secondary_y_trace_at(Scatter::new(vec![1], vec![1]), 4, 4);
+        let json: Value = serde_json::from_str(&plot.to_json()).unwrap();
+
+        assert_eq!(json["data"][0]["xaxis"], "x16");
+        assert_eq!(json["data"][0]["yaxis"], "y32");
+        let layout = &json["layout"];
+        assert_eq!(layout["grid"]["subplots"][3][3], "x16y31");
+        assert_eq!(layout["xaxis16"]["anchor"], "y31");
+        assert_eq!(layout["yaxis31"]["anchor"], "x16");
+        assert_eq!(layout["yaxis32"]["overlaying"], "y31");
+        assert_eq!(layout["yaxis32"]["side"], "right");
+
+        let plot = Subplots::new(4, 4)
+            .specs(specs)
+            .row_heights(vec![1.0, 1.0, 1.0, 1.0])
+            .vertical_spacing(0.0)
+            .build();
+        let json: Value = serde_json::from_str(&plot.to_json()).unwrap();
+        let layout = &json["layout"];
+        assert_eq!(layout["yaxis31"]["domain"], serde_json::json!([0.0, 0.25]));
+        assert_eq!(layout["yaxis"]["domain"], serde_json::json!([0.75, 1.0]));
+    }
+}
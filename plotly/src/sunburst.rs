@@ -0,0 +1,296 @@
+//! Sunburst chart
+
+use crate::common::hierarchy::{
+    BranchValues, Hierarchy, HierarchyCount, HierarchyInfo, HierarchyMarker,
+};
+use crate::common::{Dim, Domain, Font, Label, PlotType, Visible};
+use crate::pie::InsideTextOrientation;
+use crate::private;
+use crate::Trace;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Sunburst {
+    r#type: PlotType,
+    labels: Vec<String>,
+    parents: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    values: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "branchvalues")]
+    branch_values: Option<BranchValues>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<private::FlagList<HierarchyCount>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "maxdepth")]
+    max_depth: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textinfo")]
+    text_info: Option<private::FlagList<HierarchyInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<private::FlagList<HierarchyInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<HierarchyMarker>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "insidetextorientation"
+    )]
+    inside_text_orientation: Option<InsideTextOrientation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rotation: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl Sunburst {
+    pub fn new<S: AsRef<str>>(labels: Vec<S>, parents: Vec<S>) -> Box<Sunburst> {
+        Box::new(Sunburst {
+            r#type: PlotType::Sunburst,
+            labels: private::owned_string_vector(labels),
+            parents: private::owned_string_vector(parents),
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            opacity: None,
+            ids: None,
+            values: None,
+            branch_values: None,
+            count: None,
+            level: None,
+            max_depth: None,
+            text: None,
+            text_info: None,
+            text_template: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            marker: None,
+            text_font: None,
+            inside_text_font: None,
+            outside_text_font: None,
+            inside_text_orientation: None,
+            rotation: None,
+            sort: None,
+            domain: None,
+            hover_label: None,
+        })
+    }
+
+    pub fn from_hierarchy(hierarchy: Hierarchy) -> Box<Sunburst> {
+        Sunburst::new(hierarchy.labels, hierarchy.parents)
+            .ids(hierarchy.ids)
+            .values(hierarchy.values)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Sunburst> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<Sunburst> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Sunburst> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Sunburst> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Sunburst> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Sunburst> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn values(mut self, values: Vec<f64>) -> Box<Sunburst> {
+        self.values = Some(values);
+        Box::new(self)
+    }
+
+    pub fn branch_values(mut self, branch_values: BranchValues) -> Box<Sunburst> {
+        self.branch_values = Some(branch_values);
+        Box::new(self)
+    }
+
+    pub fn count(mut self, count: Vec<HierarchyCount>) -> Box<Sunburst> {
+        self.count = Some(private::FlagList { flags: count });
+        Box::new(self)
+    }
+
+    /// Sets the starting level of the hierarchy by the `ids` entry of the sector to zoom into.
+    pub fn level(mut self, level: &str) -> Box<Sunburst> {
+        self.level = Some(level.to_owned());
+        Box::new(self)
+    }
+
+    pub fn max_depth(mut self, max_depth: i32) -> Box<Sunburst> {
+        self.max_depth = Some(max_depth);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Sunburst> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Sunburst> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn text_info(mut self, text_info: Vec<HierarchyInfo>) -> Box<Sunburst> {
+        self.text_info = Some(private::FlagList { flags: text_info });
+        Box::new(self)
+    }
+
+    pub fn text_template(mut self, text_template: &str) -> Box<Sunburst> {
+        self.text_template = Some(Dim::Scalar(text_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_template_array<S: AsRef<str>>(mut self, text_template: Vec<S>) -> Box<Sunburst> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Sunburst> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Sunburst> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: Vec<HierarchyInfo>) -> Box<Sunburst> {
+        self.hover_info = Some(private::FlagList { flags: hover_info });
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Sunburst> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Sunburst> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: HierarchyMarker) -> Box<Sunburst> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<Sunburst> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    pub fn inside_text_font(mut self, inside_text_font: Font) -> Box<Sunburst> {
+        self.inside_text_font = Some(inside_text_font);
+        Box::new(self)
+    }
+
+    pub fn outside_text_font(mut self, outside_text_font: Font) -> Box<Sunburst> {
+        self.outside_text_font = Some(outside_text_font);
+        Box::new(self)
+    }
+
+    pub fn inside_text_orientation(
+        mut self,
+        inside_text_orientation: InsideTextOrientation,
+    ) -> Box<Sunburst> {
+        self.inside_text_orientation = Some(inside_text_orientation);
+        Box::new(self)
+    }
+
+    pub fn rotation(mut self, rotation: f64) -> Box<Sunburst> {
+        self.rotation = Some(rotation);
+        Box::new(self)
+    }
+
+    pub fn sort(mut self, sort: bool) -> Box<Sunburst> {
+        self.sort = Some(sort);
+        Box::new(self)
+    }
+
+    pub fn domain(mut self, domain: Domain) -> Box<Sunburst> {
+        self.domain = Some(domain);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Sunburst> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+}
+
+impl Trace for Sunburst {
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_sunburst() {
+        let trace = Sunburst::new(vec!["root", "a", "b"], vec!["", "root", "root"])
+            .count(vec![HierarchyCount::Branches, HierarchyCount::Leaves])
+            .level("root")
+            .max_depth(2)
+            .text_info(vec![HierarchyInfo::Label, HierarchyInfo::PercentParent]);
+        let expected = r#"{"type":"sunburst","labels":["root","a","b"],"parents":["","root","root"],"count":"branches+leaves","level":"root","maxdepth":2,"textinfo":"label+percent parent"}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
@@ -1,12 +1,13 @@
 //! Surface plot
 
 use crate::common::color::Color;
-use crate::common::{Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType};
+use crate::common::{Calendar, ColorBar, ColorScale, Dim, HoverInfo, Label, PlotType, Visible};
 use crate::private;
 use crate::Trace;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Lighting {
     #[serde(skip_serializing_if = "Option::is_none")]
     ambient: Option<f64>,
@@ -57,7 +58,8 @@ impl Lighting {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Position {
     x: i32,
     y: i32,
@@ -70,7 +72,8 @@ impl Position {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct PlaneProject {
     #[serde(skip_serializing_if = "Option::is_none")]
     x: Option<bool>,
@@ -105,7 +108,8 @@ impl PlaneProject {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct PlaneContours {
     #[serde(skip_serializing_if = "Option::is_none")]
     show: Option<bool>,
@@ -204,7 +208,8 @@ impl PlaneContours {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
 pub struct SurfaceContours {
     #[serde(skip_serializing_if = "Option::is_none")]
     x: Option<PlaneContours>,
@@ -239,12 +244,13 @@ impl SurfaceContours {
     }
 }
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
 pub struct Surface<X, Y, Z>
 where
     X: Serialize,
     Y: Serialize,
-    Z: num::Num + Serialize,
+    Z: Serialize,
 {
     r#type: PlotType,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -255,7 +261,11 @@ where
     #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    visible: Option<bool>,
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
     show_legend: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
@@ -308,13 +318,15 @@ where
     y_calendar: Option<Calendar>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "zcalendar")]
     z_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    scene: Option<String>,
 }
 
 impl<X, Y, Z> Surface<X, Y, Z>
 where
     X: Serialize,
     Y: Serialize,
-    Z: num::Num + Serialize,
+    Z: Serialize,
 {
     pub fn new(z: Vec<Vec<Z>>) -> Box<Surface<X, Y, Z>> {
         Box::new(Surface {
@@ -323,6 +335,8 @@ where
             y: None,
             z,
             name: None,
+            uid: None,
+            meta: None,
             visible: None,
             show_legend: None,
             legend_group: None,
@@ -350,6 +364,7 @@ where
             x_calendar: None,
             y_calendar: None,
             z_calendar: None,
+            scene: None,
         })
     }
 
@@ -368,8 +383,18 @@ where
         Box::new(self)
     }
 
-    pub fn visible(mut self, visible: bool) -> Box<Surface<X, Y, Z>> {
-        self.visible = Some(visible);
+    pub fn uid(mut self, uid: &str) -> Box<Surface<X, Y, Z>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Surface<X, Y, Z>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Surface<X, Y, Z>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
         Box::new(self)
     }
 
@@ -524,13 +549,18 @@ where
         self.z_calendar = Some(z_calendar);
         Box::new(self)
     }
+
+    pub fn scene(mut self, scene: &str) -> Box<Surface<X, Y, Z>> {
+        self.scene = Some(scene.to_owned());
+        Box::new(self)
+    }
 }
 
 impl<X, Y, Z> Trace for Surface<X, Y, Z>
 where
     X: Serialize,
     Y: Serialize,
-    Z: num::Num + Serialize,
+    Z: Serialize,
 {
     fn serialize(&self) -> String {
         serde_json::to_string(&self).unwrap()
@@ -0,0 +1,171 @@
+//! Layout templates, setting default attributes for the layout and for each trace type.
+
+use crate::{Layout, Trace};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The themes built into Python Plotly, for use with `Template::builtin`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Theme {
+    Plotly,
+    PlotlyWhite,
+    PlotlyDark,
+    Ggplot2,
+    Seaborn,
+    SimpleWhite,
+    /// Larger fonts, lines and markers. Only sets sizes, so is meant to be merged over another
+    /// theme with `Template::merge`.
+    Presentation,
+}
+
+/// A template of default attributes, set with `Layout::template`.
+///
+/// Attributes set on the `Layout` or on a trace take precedence over those of the template.
+/// Templates exported from Python Plotly may include subplots and trace types that are not
+/// supported by this crate; these are kept as they are.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Template {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<BTreeMap<String, Vec<serde_json::Value>>>,
+}
+
+impl Template {
+    pub fn new() -> Template {
+        Template {
+            layout: None,
+            data: None,
+        }
+    }
+
+    /// Load one of the built-in themes.
+    pub fn builtin(theme: Theme) -> Template {
+        let json = match theme {
+            Theme::Plotly => include_str!("../templates/template.json"),
+            Theme::PlotlyWhite => include_str!("../templates/themes/plotly_white.json"),
+            Theme::PlotlyDark => include_str!("../templates/themes/plotly_dark.json"),
+            Theme::Ggplot2 => include_str!("../templates/themes/ggplot2.json"),
+            Theme::Seaborn => include_str!("../templates/themes/seaborn.json"),
+            Theme::SimpleWhite => include_str!("../templates/themes/simple_white.json"),
+            Theme::Presentation => include_str!("../templates/themes/presentation.json"),
+        };
+        serde_json::from_str(json).unwrap()
+    }
+
+    /// Set the layout defaults of the template.
+    pub fn layout(mut self, layout: Layout) -> Template {
+        self.layout = Some(serde_json::to_value(layout).unwrap());
+        self
+    }
+
+    /// Add defaults for traces of the same type as `trace`. The data of `trace`, such as `x` and
+    /// `y`, is replaced by that of each trace using the defaults, so may be left empty.
+    ///
+    /// When several defaults are added for a trace type, they are applied in turn to the traces of
+    /// that type, cycling through them.
+    pub fn add_data(mut self, trace: Box<dyn Trace>) -> Template {
+        let trace: serde_json::Value = serde_json::from_str(&trace.serialize()).unwrap();
+        let trace_type = trace["type"].as_str().unwrap_or("scatter").to_owned();
+        self.data
+            .get_or_insert_with(BTreeMap::new)
+            .entry(trace_type)
+            .or_default()
+            .push(trace);
+        self
+    }
+
+    /// Merge `other` over this template: attributes set in both are taken from `other`, and the
+    /// defaults of each trace type are merged in order.
+    pub fn merge(mut self, other: Template) -> Template {
+        self.layout = match (self.layout, other.layout) {
+            (Some(mut layout), Some(other)) => {
+                merge_values(&mut layout, other);
+                Some(layout)
+            }
+            (layout, other) => other.or(layout),
+        };
+        if let Some(other) = other.data {
+            let data = self.data.get_or_insert_with(BTreeMap::new);
+            for (trace_type, other) in other {
+                let defaults = data.entry(trace_type).or_default();
+                for (idx, other) in other.into_iter().enumerate() {
+                    match defaults.get_mut(idx) {
+                        Some(trace) => merge_values(trace, other),
+                        None => defaults.push(other),
+                    }
+                }
+            }
+        }
+        self
+    }
+}
+
+fn merge_values(value: &mut serde_json::Value, other: serde_json::Value) {
+    match (value, other) {
+        (serde_json::Value::Object(value), serde_json::Value::Object(other)) => {
+            for (key, other) in other {
+                match value.get_mut(&key) {
+                    Some(value) => merge_values(value, other),
+                    None => {
+                        value.insert(key, other);
+                    }
+                }
+            }
+        }
+        (value, other) => *value = other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{Font, Marker, Title};
+    use crate::Scatter;
+
+    #[test]
+    fn test_builtin_themes() {
+        let themes = [
+            Theme::Plotly,
+            Theme::PlotlyWhite,
+            Theme::PlotlyDark,
+            Theme::Ggplot2,
+            Theme::Seaborn,
+            Theme::SimpleWhite,
+            Theme::Presentation,
+        ];
+        for theme in themes.iter() {
+            let template = Template::builtin(*theme);
+            assert!(template.layout.is_some() && template.data.is_some());
+        }
+    }
+
+    #[test]
+    fn test_merge_templates() {
+        let template = Template::builtin(Theme::PlotlyDark)
+            .merge(Template::builtin(Theme::Presentation))
+            .merge(
+                Template::new()
+                    .layout(
+                        Layout::new()
+                            .title(Title::new("title"))
+                            .font(Font::new().size(20)),
+                    )
+                    .add_data(
+                        Scatter::new(Vec::<f64>::new(), Vec::<f64>::new())
+                            .marker(Marker::new().size(12)),
+                    ),
+            );
+        let layout = template.layout.unwrap();
+        assert_eq!(layout["font"]["color"], "#f2f5fa");
+        assert_eq!(layout["font"]["size"], 20);
+        assert_eq!(layout["title"]["text"], "title");
+        assert_eq!(layout["title"]["x"], 0.05);
+
+        let scatter = &template.data.unwrap()["scatter"][0];
+        assert_eq!(scatter["marker"]["size"], 12);
+        assert_eq!(scatter["marker"]["colorbar"]["outlinewidth"], 0);
+        assert_eq!(scatter["line"]["width"], 3);
+    }
+}
@@ -0,0 +1,440 @@
+//! Treemap chart
+
+use crate::common::hierarchy::{
+    BranchValues, Hierarchy, HierarchyCount, HierarchyInfo, HierarchyMarker,
+};
+use crate::common::{Dim, Domain, Font, Label, PlotType, Position, Side, Visible};
+use crate::private;
+use crate::Trace;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Packing {
+    #[serde(rename = "squarify")]
+    Squarify,
+    #[serde(rename = "binary")]
+    Binary,
+    #[serde(rename = "dice")]
+    Dice,
+    #[serde(rename = "slice")]
+    Slice,
+    #[serde(rename = "slice-dice")]
+    SliceDice,
+    #[serde(rename = "dice-slice")]
+    DiceSlice,
+}
+
+/// The directions in which a `Tiling` is flipped. Both flags may be combined.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum TilingFlip {
+    #[serde(rename = "x")]
+    X,
+    #[serde(rename = "y")]
+    Y,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Tiling {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    packing: Option<Packing>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "squarifyratio")]
+    squarify_ratio: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    flip: Option<private::FlagList<TilingFlip>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pad: Option<f64>,
+}
+
+impl Tiling {
+    pub fn new() -> Tiling {
+        Tiling {
+            packing: None,
+            squarify_ratio: None,
+            flip: None,
+            pad: None,
+        }
+    }
+
+    pub fn packing(mut self, packing: Packing) -> Tiling {
+        self.packing = Some(packing);
+        self
+    }
+
+    pub fn squarify_ratio(mut self, squarify_ratio: f64) -> Tiling {
+        self.squarify_ratio = Some(squarify_ratio);
+        self
+    }
+
+    pub fn flip(mut self, flip: Vec<TilingFlip>) -> Tiling {
+        self.flip = Some(private::FlagList { flags: flip });
+        self
+    }
+
+    pub fn pad(mut self, pad: f64) -> Tiling {
+        self.pad = Some(pad);
+        self
+    }
+}
+
+/// The shape of the edges between the items of a `PathBar`.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum EdgeShape {
+    #[serde(rename = ">")]
+    GreaterThan,
+    #[serde(rename = "<")]
+    LessThan,
+    #[serde(rename = "|")]
+    VerticalBar,
+    #[serde(rename = "/")]
+    Slash,
+    #[serde(rename = "\\")]
+    Backslash,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct PathBar {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<Side>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "edgeshape")]
+    edge_shape: Option<EdgeShape>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thickness: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+}
+
+impl PathBar {
+    pub fn new() -> PathBar {
+        PathBar {
+            visible: None,
+            side: None,
+            edge_shape: None,
+            thickness: None,
+            text_font: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> PathBar {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn side(mut self, side: Side) -> PathBar {
+        self.side = Some(side);
+        self
+    }
+
+    pub fn edge_shape(mut self, edge_shape: EdgeShape) -> PathBar {
+        self.edge_shape = Some(edge_shape);
+        self
+    }
+
+    pub fn thickness(mut self, thickness: f64) -> PathBar {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> PathBar {
+        self.text_font = Some(text_font);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Treemap {
+    r#type: PlotType,
+    labels: Vec<String>,
+    parents: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    values: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "branchvalues")]
+    branch_values: Option<BranchValues>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<private::FlagList<HierarchyCount>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "maxdepth")]
+    max_depth: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textinfo")]
+    text_info: Option<private::FlagList<HierarchyInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<private::FlagList<HierarchyInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<HierarchyMarker>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textposition")]
+    text_position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tiling: Option<Tiling>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "pathbar")]
+    path_bar: Option<PathBar>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    domain: Option<Domain>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+}
+
+impl Treemap {
+    pub fn new<S: AsRef<str>>(labels: Vec<S>, parents: Vec<S>) -> Box<Treemap> {
+        Box::new(Treemap {
+            r#type: PlotType::Treemap,
+            labels: private::owned_string_vector(labels),
+            parents: private::owned_string_vector(parents),
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            opacity: None,
+            ids: None,
+            values: None,
+            branch_values: None,
+            count: None,
+            level: None,
+            max_depth: None,
+            text: None,
+            text_info: None,
+            text_template: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            marker: None,
+            text_font: None,
+            inside_text_font: None,
+            outside_text_font: None,
+            text_position: None,
+            sort: None,
+            tiling: None,
+            path_bar: None,
+            domain: None,
+            hover_label: None,
+        })
+    }
+
+    pub fn from_hierarchy(hierarchy: Hierarchy) -> Box<Treemap> {
+        Treemap::new(hierarchy.labels, hierarchy.parents)
+            .ids(hierarchy.ids)
+            .values(hierarchy.values)
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Treemap> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<Treemap> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Treemap> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Treemap> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Treemap> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Treemap> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn values(mut self, values: Vec<f64>) -> Box<Treemap> {
+        self.values = Some(values);
+        Box::new(self)
+    }
+
+    pub fn branch_values(mut self, branch_values: BranchValues) -> Box<Treemap> {
+        self.branch_values = Some(branch_values);
+        Box::new(self)
+    }
+
+    pub fn count(mut self, count: Vec<HierarchyCount>) -> Box<Treemap> {
+        self.count = Some(private::FlagList { flags: count });
+        Box::new(self)
+    }
+
+    /// Sets the starting level of the hierarchy by the `ids` entry of the sector to zoom into.
+    pub fn level(mut self, level: &str) -> Box<Treemap> {
+        self.level = Some(level.to_owned());
+        Box::new(self)
+    }
+
+    pub fn max_depth(mut self, max_depth: i32) -> Box<Treemap> {
+        self.max_depth = Some(max_depth);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Treemap> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Treemap> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn text_info(mut self, text_info: Vec<HierarchyInfo>) -> Box<Treemap> {
+        self.text_info = Some(private::FlagList { flags: text_info });
+        Box::new(self)
+    }
+
+    pub fn text_template(mut self, text_template: &str) -> Box<Treemap> {
+        self.text_template = Some(Dim::Scalar(text_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_template_array<S: AsRef<str>>(mut self, text_template: Vec<S>) -> Box<Treemap> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Treemap> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Treemap> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: Vec<HierarchyInfo>) -> Box<Treemap> {
+        self.hover_info = Some(private::FlagList { flags: hover_info });
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Treemap> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(mut self, hover_template: Vec<S>) -> Box<Treemap> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: HierarchyMarker) -> Box<Treemap> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<Treemap> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    pub fn inside_text_font(mut self, inside_text_font: Font) -> Box<Treemap> {
+        self.inside_text_font = Some(inside_text_font);
+        Box::new(self)
+    }
+
+    pub fn outside_text_font(mut self, outside_text_font: Font) -> Box<Treemap> {
+        self.outside_text_font = Some(outside_text_font);
+        Box::new(self)
+    }
+
+    pub fn text_position(mut self, text_position: Position) -> Box<Treemap> {
+        self.text_position = Some(text_position);
+        Box::new(self)
+    }
+
+    pub fn sort(mut self, sort: bool) -> Box<Treemap> {
+        self.sort = Some(sort);
+        Box::new(self)
+    }
+
+    pub fn tiling(mut self, tiling: Tiling) -> Box<Treemap> {
+        self.tiling = Some(tiling);
+        Box::new(self)
+    }
+
+    pub fn path_bar(mut self, path_bar: PathBar) -> Box<Treemap> {
+        self.path_bar = Some(path_bar);
+        Box::new(self)
+    }
+
+    pub fn domain(mut self, domain: Domain) -> Box<Treemap> {
+        self.domain = Some(domain);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Treemap> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+}
+
+impl Trace for Treemap {
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize_treemap() {
+        let trace = Treemap::new(vec!["root", "a"], vec!["", "root"])
+            .count(vec![HierarchyCount::Leaves])
+            .tiling(
+                Tiling::new()
+                    .packing(Packing::SliceDice)
+                    .flip(vec![TilingFlip::X, TilingFlip::Y]),
+            )
+            .path_bar(
+                PathBar::new()
+                    .side(Side::Bottom)
+                    .edge_shape(EdgeShape::Backslash),
+            );
+        let expected = r#"{"type":"treemap","labels":["root","a"],"parents":["","root"],"count":"leaves","tiling":{"packing":"slice-dice","flip":"x+y"},"pathbar":{"side":"bottom","edgeshape":"\\"}}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
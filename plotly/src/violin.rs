@@ -0,0 +1,604 @@
+//! Violin plot
+
+use crate::box_plot::{BoxPoints, QuartileMethod};
+use crate::common::color::Color;
+use crate::common::{
+    Calendar, Dim, HoverInfo, Label, Line, Marker, Orientation, PlotType, Visible,
+};
+use crate::private;
+use crate::Trace;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ViolinSide {
+    #[serde(rename = "both")]
+    Both,
+    #[serde(rename = "positive")]
+    Positive,
+    #[serde(rename = "negative")]
+    Negative,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ScaleMode {
+    #[serde(rename = "width")]
+    Width,
+    #[serde(rename = "count")]
+    Count,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum SpanMode {
+    #[serde(rename = "soft")]
+    Soft,
+    #[serde(rename = "hard")]
+    Hard,
+    #[serde(rename = "manual")]
+    Manual,
+}
+
+/// Flags for `Violin::hover_on`, e.g. `vec![ViolinHoverOn::Violins, ViolinHoverOn::Kde]`.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ViolinHoverOn {
+    #[serde(rename = "violins")]
+    Violins,
+    #[serde(rename = "points")]
+    Points,
+    #[serde(rename = "kde")]
+    Kde,
+    #[serde(rename = "all")]
+    All,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct MeanLine {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+}
+
+impl MeanLine {
+    pub fn new() -> MeanLine {
+        MeanLine {
+            visible: None,
+            color: None,
+            width: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> MeanLine {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn color<C: Color>(mut self, color: C) -> MeanLine {
+        self.color = Some(color.to_color_string());
+        self
+    }
+
+    pub fn width(mut self, width: f64) -> MeanLine {
+        self.width = Some(width);
+        self
+    }
+}
+
+/// The box plot drawn inside the violin.
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ViolinBox {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
+    fill_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+}
+
+impl ViolinBox {
+    pub fn new() -> ViolinBox {
+        ViolinBox {
+            visible: None,
+            width: None,
+            fill_color: None,
+            line: None,
+        }
+    }
+
+    pub fn visible(mut self, visible: bool) -> ViolinBox {
+        self.visible = Some(visible);
+        self
+    }
+
+    pub fn width(mut self, width: f64) -> ViolinBox {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn fill_color<C: Color>(mut self, fill_color: C) -> ViolinBox {
+        self.fill_color = Some(fill_color.to_color_string());
+        self
+    }
+
+    pub fn line(mut self, line: Line) -> ViolinBox {
+        self.line = Some(line);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Violin<Y, X>
+where
+    Y: Serialize,
+    X: Serialize,
+{
+    r#type: PlotType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<Vec<X>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<Vec<Y>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orientation: Option<Orientation>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "alignmentgroup")]
+    alignment_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "offsetgroup")]
+    offset_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    points: Option<private::TruthyEnum<BoxPoints>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "pointpos")]
+    point_pos: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jitter: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<ViolinSide>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bandwidth: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "scalegroup")]
+    scale_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "scalemode")]
+    scale_mode: Option<ScaleMode>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "spanmode")]
+    span_mode: Option<SpanMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    span: Option<Vec<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "meanline")]
+    mean_line: Option<MeanLine>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    r#box: Option<ViolinBox>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "quartilemethod")]
+    quartile_method: Option<QuartileMethod>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "fillcolor")]
+    fill_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoveron")]
+    hover_on: Option<private::FlagList<ViolinHoverOn>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xcalendar")]
+    x_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ycalendar")]
+    y_calendar: Option<Calendar>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<String>,
+}
+
+impl<Y> Violin<Y, f64>
+where
+    Y: Serialize,
+{
+    pub fn new(y: Vec<Y>) -> Box<Violin<Y, f64>> {
+        Box::new(Violin {
+            r#type: PlotType::Violin,
+            x: None,
+            y: Some(y),
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            ids: None,
+            width: None,
+            text: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            orientation: None,
+            alignment_group: None,
+            offset_group: None,
+            marker: None,
+            line: None,
+            points: None,
+            point_pos: None,
+            jitter: None,
+            side: None,
+            bandwidth: None,
+            scale_group: None,
+            scale_mode: None,
+            span_mode: None,
+            span: None,
+            mean_line: None,
+            r#box: None,
+            quartile_method: None,
+            fill_color: None,
+            hover_label: None,
+            hover_on: None,
+            x_calendar: None,
+            y_calendar: None,
+            x_axis: None,
+            y_axis: None,
+        })
+    }
+}
+
+impl<Y, X> Violin<Y, X>
+where
+    Y: Serialize,
+    X: Serialize,
+{
+    pub fn new_xy(x: Vec<X>, y: Vec<Y>) -> Box<Violin<Y, X>> {
+        Box::new(Violin {
+            r#type: PlotType::Violin,
+            x: Some(x),
+            y: Some(y),
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            ids: None,
+            width: None,
+            text: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            orientation: None,
+            alignment_group: None,
+            offset_group: None,
+            marker: None,
+            line: None,
+            points: None,
+            point_pos: None,
+            jitter: None,
+            side: None,
+            bandwidth: None,
+            scale_group: None,
+            scale_mode: None,
+            span_mode: None,
+            span: None,
+            mean_line: None,
+            r#box: None,
+            quartile_method: None,
+            fill_color: None,
+            hover_label: None,
+            hover_on: None,
+            x_calendar: None,
+            y_calendar: None,
+            x_axis: None,
+            y_axis: None,
+        })
+    }
+
+    pub fn horizontal(x: Vec<X>) -> Box<Violin<f64, X>> {
+        Box::new(Violin {
+            r#type: PlotType::Violin,
+            x: Some(x),
+            y: None,
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            ids: None,
+            width: None,
+            text: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            orientation: None,
+            alignment_group: None,
+            offset_group: None,
+            marker: None,
+            line: None,
+            points: None,
+            point_pos: None,
+            jitter: None,
+            side: None,
+            bandwidth: None,
+            scale_group: None,
+            scale_mode: None,
+            span_mode: None,
+            span: None,
+            mean_line: None,
+            r#box: None,
+            quartile_method: None,
+            fill_color: None,
+            hover_label: None,
+            hover_on: None,
+            x_calendar: None,
+            y_calendar: None,
+            x_axis: None,
+            y_axis: None,
+        })
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Violin<Y, X>> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<Violin<Y, X>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Violin<Y, X>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Violin<Y, X>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Violin<Y, X>> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Violin<Y, X>> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Violin<Y, X>> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Violin<Y, X>> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn width(mut self, width: f64) -> Box<Violin<Y, X>> {
+        self.width = Some(width);
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Violin<Y, X>> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Violin<Y, X>> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Violin<Y, X>> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Violin<Y, X>> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Violin<Y, X>> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Violin<Y, X>> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(
+        mut self,
+        hover_template: Vec<S>,
+    ) -> Box<Violin<Y, X>> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Box<Violin<Y, X>> {
+        self.orientation = Some(orientation);
+        Box::new(self)
+    }
+
+    pub fn alignment_group(mut self, alignment_group: &str) -> Box<Violin<Y, X>> {
+        self.alignment_group = Some(alignment_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn offset_group(mut self, offset_group: &str) -> Box<Violin<Y, X>> {
+        self.offset_group = Some(offset_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn marker(mut self, marker: Marker) -> Box<Violin<Y, X>> {
+        self.marker = Some(marker);
+        Box::new(self)
+    }
+
+    pub fn line(mut self, line: Line) -> Box<Violin<Y, X>> {
+        self.line = Some(line);
+        Box::new(self)
+    }
+
+    pub fn points(mut self, points: BoxPoints) -> Box<Violin<Y, X>> {
+        self.points = Some(private::TruthyEnum { e: points });
+        Box::new(self)
+    }
+
+    pub fn inner_box(mut self, inner_box: ViolinBox) -> Box<Violin<Y, X>> {
+        self.r#box = Some(inner_box);
+        Box::new(self)
+    }
+
+    pub fn point_pos(mut self, point_pos: f64) -> Box<Violin<Y, X>> {
+        self.point_pos = Some(point_pos);
+        Box::new(self)
+    }
+
+    pub fn jitter(mut self, jitter: f64) -> Box<Violin<Y, X>> {
+        self.jitter = Some(jitter);
+        Box::new(self)
+    }
+
+    pub fn side(mut self, side: ViolinSide) -> Box<Violin<Y, X>> {
+        self.side = Some(side);
+        Box::new(self)
+    }
+
+    pub fn bandwidth(mut self, bandwidth: f64) -> Box<Violin<Y, X>> {
+        self.bandwidth = Some(bandwidth);
+        Box::new(self)
+    }
+
+    pub fn scale_group(mut self, scale_group: &str) -> Box<Violin<Y, X>> {
+        self.scale_group = Some(scale_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn scale_mode(mut self, scale_mode: ScaleMode) -> Box<Violin<Y, X>> {
+        self.scale_mode = Some(scale_mode);
+        Box::new(self)
+    }
+
+    pub fn span_mode(mut self, span_mode: SpanMode) -> Box<Violin<Y, X>> {
+        self.span_mode = Some(span_mode);
+        Box::new(self)
+    }
+
+    pub fn span(mut self, span: Vec<f64>) -> Box<Violin<Y, X>> {
+        self.span = Some(span);
+        Box::new(self)
+    }
+
+    pub fn mean_line(mut self, mean_line: MeanLine) -> Box<Violin<Y, X>> {
+        self.mean_line = Some(mean_line);
+        Box::new(self)
+    }
+
+    pub fn quartile_method(mut self, quartile_method: QuartileMethod) -> Box<Violin<Y, X>> {
+        self.quartile_method = Some(quartile_method);
+        Box::new(self)
+    }
+
+    pub fn fill_color<C: Color>(mut self, fill_color: C) -> Box<Violin<Y, X>> {
+        self.fill_color = Some(fill_color.to_color_string());
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Violin<Y, X>> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn hover_on(mut self, hover_on: Vec<ViolinHoverOn>) -> Box<Violin<Y, X>> {
+        self.hover_on = Some(private::FlagList { flags: hover_on });
+        Box::new(self)
+    }
+
+    pub fn x_calendar(mut self, x_calendar: Calendar) -> Box<Violin<Y, X>> {
+        self.x_calendar = Some(x_calendar);
+        Box::new(self)
+    }
+
+    pub fn y_calendar(mut self, y_calendar: Calendar) -> Box<Violin<Y, X>> {
+        self.y_calendar = Some(y_calendar);
+        Box::new(self)
+    }
+
+    pub fn x_axis(mut self, x_axis: &str) -> Box<Violin<Y, X>> {
+        self.x_axis = Some(x_axis.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, y_axis: &str) -> Box<Violin<Y, X>> {
+        self.y_axis = Some(y_axis.to_owned());
+        Box::new(self)
+    }
+}
+
+impl<Y, X> Trace for Violin<Y, X>
+where
+    Y: Serialize,
+    X: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::color::NamedColor;
+
+    #[test]
+    fn test_serialize_violin() {
+        let trace = Violin::new(vec![1.0, 2.0, 2.5])
+            .points(BoxPoints::False)
+            .side(ViolinSide::Positive)
+            .scale_mode(ScaleMode::Count)
+            .span_mode(SpanMode::Manual)
+            .span(vec![0.0, 3.0])
+            .mean_line(MeanLine::new().visible(true).width(2.0))
+            .inner_box(ViolinBox::new().visible(true).fill_color(NamedColor::White))
+            .hover_on(vec![ViolinHoverOn::Violins, ViolinHoverOn::Kde]);
+        let expected = r#"{"type":"violin","y":[1.0,2.0,2.5],"points":false,"side":"positive","scalemode":"count","spanmode":"manual","span":[0.0,3.0],"meanline":{"visible":true,"width":2.0},"box":{"visible":true,"fillcolor":"white"},"hoveron":"violins+kde"}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
@@ -0,0 +1,494 @@
+//! Waterfall chart
+
+use crate::common::{
+    ConstrainText, Dim, Font, HoverInfo, Label, Line, Marker, Orientation, PlotType, TextAnchor,
+    TextPosition, Visible,
+};
+use crate::private;
+use crate::Trace;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum Measure {
+    #[serde(rename = "relative")]
+    Relative,
+    #[serde(rename = "total")]
+    Total,
+    #[serde(rename = "absolute")]
+    Absolute,
+}
+
+/// The information shown on the bars of a `Waterfall` with `Waterfall::text_info`. Several flags
+/// are combined, e.g. `vec![WaterfallTextInfo::Label, WaterfallTextInfo::Delta]`.
+#[derive(Serialize, Deserialize, Debug)]
+pub enum WaterfallTextInfo {
+    #[serde(rename = "label")]
+    Label,
+    #[serde(rename = "text")]
+    Text,
+    #[serde(rename = "initial")]
+    Initial,
+    #[serde(rename = "delta")]
+    Delta,
+    #[serde(rename = "final")]
+    Final,
+    #[serde(rename = "none")]
+    None,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub enum ConnectorMode {
+    #[serde(rename = "spanning")]
+    Spanning,
+    #[serde(rename = "between")]
+    Between,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Connector {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<Line>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mode: Option<ConnectorMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+}
+
+impl Connector {
+    pub fn new() -> Connector {
+        Connector {
+            line: None,
+            mode: None,
+            visible: None,
+        }
+    }
+
+    pub fn line(mut self, line: Line) -> Connector {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn mode(mut self, mode: ConnectorMode) -> Connector {
+        self.mode = Some(mode);
+        self
+    }
+
+    pub fn visible(mut self, visible: bool) -> Connector {
+        self.visible = Some(visible);
+        self
+    }
+}
+
+/// Styling for the increasing, decreasing or total bars of a `Waterfall` trace.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct BarStyle {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<Marker>,
+}
+
+impl BarStyle {
+    pub fn new() -> BarStyle {
+        BarStyle { marker: None }
+    }
+
+    pub fn marker(mut self, marker: Marker) -> BarStyle {
+        self.marker = Some(marker);
+        self
+    }
+}
+
+impl Default for BarStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Waterfall<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    r#type: PlotType,
+    x: Vec<X>,
+    y: Vec<Y>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<private::TruthyEnum<Visible>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "showlegend")]
+    show_legend: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "legendgroup")]
+    legend_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    opacity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    measure: Option<Vec<Measure>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<Dim<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<Dim<f64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textposition")]
+    text_position: Option<Dim<TextPosition>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "texttemplate")]
+    text_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textinfo")]
+    text_info: Option<private::FlagList<WaterfallTextInfo>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertext")]
+    hover_text: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverinfo")]
+    hover_info: Option<HoverInfo>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hovertemplate")]
+    hover_template: Option<Dim<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    orientation: Option<Orientation>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "alignmentgroup")]
+    alignment_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "offsetgroup")]
+    offset_group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connector: Option<Connector>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    increasing: Option<BarStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decreasing: Option<BarStyle>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    totals: Option<BarStyle>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textangle")]
+    text_angle: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "textfont")]
+    text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "insidetextfont")]
+    inside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "outsidetextfont")]
+    outside_text_font: Option<Font>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "insidetextanchor")]
+    inside_text_anchor: Option<TextAnchor>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "constraintext")]
+    constrain_text: Option<ConstrainText>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "cliponaxis")]
+    clip_on_axis: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "hoverlabel")]
+    hover_label: Option<Label>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "xaxis")]
+    x_axis: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "yaxis")]
+    y_axis: Option<String>,
+}
+
+impl<X, Y> Waterfall<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    pub fn new(x: Vec<X>, y: Vec<Y>) -> Box<Waterfall<X, Y>> {
+        Box::new(Waterfall {
+            r#type: PlotType::Waterfall,
+            x,
+            y,
+            name: None,
+            uid: None,
+            meta: None,
+            visible: None,
+            show_legend: None,
+            legend_group: None,
+            opacity: None,
+            ids: None,
+            measure: None,
+            base: None,
+            width: None,
+            offset: None,
+            text: None,
+            text_position: None,
+            text_template: None,
+            text_info: None,
+            hover_text: None,
+            hover_info: None,
+            hover_template: None,
+            orientation: None,
+            alignment_group: None,
+            offset_group: None,
+            connector: None,
+            increasing: None,
+            decreasing: None,
+            totals: None,
+            text_angle: None,
+            text_font: None,
+            inside_text_font: None,
+            outside_text_font: None,
+            inside_text_anchor: None,
+            constrain_text: None,
+            clip_on_axis: None,
+            hover_label: None,
+            x_axis: None,
+            y_axis: None,
+        })
+    }
+
+    pub fn name(mut self, name: &str) -> Box<Waterfall<X, Y>> {
+        self.name = Some(name.to_owned());
+        Box::new(self)
+    }
+
+    pub fn uid(mut self, uid: &str) -> Box<Waterfall<X, Y>> {
+        self.uid = Some(uid.to_owned());
+        Box::new(self)
+    }
+
+    pub fn meta<M: Serialize>(mut self, meta: M) -> Box<Waterfall<X, Y>> {
+        self.meta = Some(serde_json::to_value(meta).unwrap());
+        Box::new(self)
+    }
+
+    pub fn visible<V: Into<Visible>>(mut self, visible: V) -> Box<Waterfall<X, Y>> {
+        self.visible = Some(private::TruthyEnum { e: visible.into() });
+        Box::new(self)
+    }
+
+    pub fn show_legend(mut self, show_legend: bool) -> Box<Waterfall<X, Y>> {
+        self.show_legend = Some(show_legend);
+        Box::new(self)
+    }
+
+    pub fn legend_group(mut self, legend_group: &str) -> Box<Waterfall<X, Y>> {
+        self.legend_group = Some(legend_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn opacity(mut self, opacity: f64) -> Box<Waterfall<X, Y>> {
+        self.opacity = Some(opacity);
+        Box::new(self)
+    }
+
+    pub fn ids<S: AsRef<str>>(mut self, ids: Vec<S>) -> Box<Waterfall<X, Y>> {
+        let ids = private::owned_string_vector(ids);
+        self.ids = Some(ids);
+        Box::new(self)
+    }
+
+    pub fn measure(mut self, measure: Vec<Measure>) -> Box<Waterfall<X, Y>> {
+        self.measure = Some(measure);
+        Box::new(self)
+    }
+
+    pub fn base(mut self, base: f64) -> Box<Waterfall<X, Y>> {
+        self.base = Some(base);
+        Box::new(self)
+    }
+
+    pub fn width(mut self, width: f64) -> Box<Waterfall<X, Y>> {
+        self.width = Some(Dim::Scalar(width));
+        Box::new(self)
+    }
+
+    pub fn width_array(mut self, width: Vec<f64>) -> Box<Waterfall<X, Y>> {
+        self.width = Some(Dim::Vector(width));
+        Box::new(self)
+    }
+
+    pub fn offset(mut self, offset: f64) -> Box<Waterfall<X, Y>> {
+        self.offset = Some(Dim::Scalar(offset));
+        Box::new(self)
+    }
+
+    pub fn offset_array(mut self, offset: Vec<f64>) -> Box<Waterfall<X, Y>> {
+        self.offset = Some(Dim::Vector(offset));
+        Box::new(self)
+    }
+
+    pub fn text(mut self, text: &str) -> Box<Waterfall<X, Y>> {
+        self.text = Some(Dim::Scalar(text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_array<S: AsRef<str>>(mut self, text: Vec<S>) -> Box<Waterfall<X, Y>> {
+        let text = private::owned_string_vector(text);
+        self.text = Some(Dim::Vector(text));
+        Box::new(self)
+    }
+
+    pub fn text_position(mut self, text_position: TextPosition) -> Box<Waterfall<X, Y>> {
+        self.text_position = Some(Dim::Scalar(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_position_array(mut self, text_position: Vec<TextPosition>) -> Box<Waterfall<X, Y>> {
+        self.text_position = Some(Dim::Vector(text_position));
+        Box::new(self)
+    }
+
+    pub fn text_template(mut self, text_template: &str) -> Box<Waterfall<X, Y>> {
+        self.text_template = Some(Dim::Scalar(text_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn text_template_array<S: AsRef<str>>(
+        mut self,
+        text_template: Vec<S>,
+    ) -> Box<Waterfall<X, Y>> {
+        let text_template = private::owned_string_vector(text_template);
+        self.text_template = Some(Dim::Vector(text_template));
+        Box::new(self)
+    }
+
+    pub fn text_info(mut self, text_info: Vec<WaterfallTextInfo>) -> Box<Waterfall<X, Y>> {
+        self.text_info = Some(private::FlagList { flags: text_info });
+        Box::new(self)
+    }
+
+    pub fn hover_text(mut self, hover_text: &str) -> Box<Waterfall<X, Y>> {
+        self.hover_text = Some(Dim::Scalar(hover_text.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_text_array<S: AsRef<str>>(mut self, hover_text: Vec<S>) -> Box<Waterfall<X, Y>> {
+        let hover_text = private::owned_string_vector(hover_text);
+        self.hover_text = Some(Dim::Vector(hover_text));
+        Box::new(self)
+    }
+
+    pub fn hover_info(mut self, hover_info: HoverInfo) -> Box<Waterfall<X, Y>> {
+        self.hover_info = Some(hover_info);
+        Box::new(self)
+    }
+
+    pub fn hover_template(mut self, hover_template: &str) -> Box<Waterfall<X, Y>> {
+        self.hover_template = Some(Dim::Scalar(hover_template.to_owned()));
+        Box::new(self)
+    }
+
+    pub fn hover_template_array<S: AsRef<str>>(
+        mut self,
+        hover_template: Vec<S>,
+    ) -> Box<Waterfall<X, Y>> {
+        let hover_template = private::owned_string_vector(hover_template);
+        self.hover_template = Some(Dim::Vector(hover_template));
+        Box::new(self)
+    }
+
+    pub fn orientation(mut self, orientation: Orientation) -> Box<Waterfall<X, Y>> {
+        self.orientation = Some(orientation);
+        Box::new(self)
+    }
+
+    pub fn alignment_group(mut self, alignment_group: &str) -> Box<Waterfall<X, Y>> {
+        self.alignment_group = Some(alignment_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn offset_group(mut self, offset_group: &str) -> Box<Waterfall<X, Y>> {
+        self.offset_group = Some(offset_group.to_owned());
+        Box::new(self)
+    }
+
+    pub fn connector(mut self, connector: Connector) -> Box<Waterfall<X, Y>> {
+        self.connector = Some(connector);
+        Box::new(self)
+    }
+
+    pub fn increasing(mut self, increasing: BarStyle) -> Box<Waterfall<X, Y>> {
+        self.increasing = Some(increasing);
+        Box::new(self)
+    }
+
+    pub fn decreasing(mut self, decreasing: BarStyle) -> Box<Waterfall<X, Y>> {
+        self.decreasing = Some(decreasing);
+        Box::new(self)
+    }
+
+    pub fn totals(mut self, totals: BarStyle) -> Box<Waterfall<X, Y>> {
+        self.totals = Some(totals);
+        Box::new(self)
+    }
+
+    pub fn text_angle(mut self, text_angle: f64) -> Box<Waterfall<X, Y>> {
+        self.text_angle = Some(text_angle);
+        Box::new(self)
+    }
+
+    pub fn text_font(mut self, text_font: Font) -> Box<Waterfall<X, Y>> {
+        self.text_font = Some(text_font);
+        Box::new(self)
+    }
+
+    pub fn inside_text_font(mut self, inside_text_font: Font) -> Box<Waterfall<X, Y>> {
+        self.inside_text_font = Some(inside_text_font);
+        Box::new(self)
+    }
+
+    pub fn outside_text_font(mut self, outside_text_font: Font) -> Box<Waterfall<X, Y>> {
+        self.outside_text_font = Some(outside_text_font);
+        Box::new(self)
+    }
+
+    pub fn inside_text_anchor(mut self, inside_text_anchor: TextAnchor) -> Box<Waterfall<X, Y>> {
+        self.inside_text_anchor = Some(inside_text_anchor);
+        Box::new(self)
+    }
+
+    pub fn constrain_text(mut self, constrain_text: ConstrainText) -> Box<Waterfall<X, Y>> {
+        self.constrain_text = Some(constrain_text);
+        Box::new(self)
+    }
+
+    pub fn clip_on_axis(mut self, clip_on_axis: bool) -> Box<Waterfall<X, Y>> {
+        self.clip_on_axis = Some(clip_on_axis);
+        Box::new(self)
+    }
+
+    pub fn hover_label(mut self, hover_label: Label) -> Box<Waterfall<X, Y>> {
+        self.hover_label = Some(hover_label);
+        Box::new(self)
+    }
+
+    pub fn x_axis(mut self, x_axis: &str) -> Box<Waterfall<X, Y>> {
+        self.x_axis = Some(x_axis.to_owned());
+        Box::new(self)
+    }
+
+    pub fn y_axis(mut self, y_axis: &str) -> Box<Waterfall<X, Y>> {
+        self.y_axis = Some(y_axis.to_owned());
+        Box::new(self)
+    }
+}
+
+impl<X, Y> Trace for Waterfall<X, Y>
+where
+    X: Serialize,
+    Y: Serialize,
+{
+    fn serialize(&self) -> String {
+        serde_json::to_string(&self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::color::NamedColor;
+
+    #[test]
+    fn test_serialize_waterfall() {
+        let trace = Waterfall::new(vec!["a", "b", "c"], vec![10, -4, 6])
+            .measure(vec![Measure::Absolute, Measure::Relative, Measure::Total])
+            .text_info(vec![WaterfallTextInfo::Initial, WaterfallTextInfo::Delta])
+            .connector(Connector::new().mode(ConnectorMode::Between).visible(false))
+            .increasing(BarStyle::new().marker(Marker::new().color(NamedColor::Green)))
+            .constrain_text(ConstrainText::Both);
+        let expected = r#"{"type":"waterfall","x":["a","b","c"],"y":[10,-4,6],"measure":["absolute","relative","total"],"textinfo":"initial+delta","connector":{"mode":"between","visible":false},"increasing":{"marker":{"color":"green"}},"constraintext":"both"}"#;
+        assert_eq!(serde_json::to_string(&trace).unwrap(), expected);
+    }
+}
@@ -57,10 +57,53 @@ Run the installation executable with the default target path. After that `plotly
 extern crate rand;
 use rand::Rng;
 use std::env;
+use std::fmt;
 use std::fs::File;
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, ExitStatus};
+
+/// Errors that can occur while locating or running the Orca executable.
+#[derive(Debug)]
+pub enum Error {
+    /// The Orca executable could not be found in the expected location.
+    OrcaNotFound,
+    /// Reading or writing an intermediate file, or spawning Orca, failed.
+    Io(std::io::Error),
+    /// Orca ran but exited with a non-zero status.
+    OrcaFailed { status: ExitStatus, stderr: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::OrcaNotFound => write!(
+                f,
+                "could not find the orca executable\n{}",
+                ORCA_INSTALLATION_INSTRUCTIONS
+            ),
+            Error::Io(e) => write!(f, "orca i/o error: {}", e),
+            Error::OrcaFailed { status, stderr } => {
+                write!(f, "orca exited with {}: {}", status, stderr.trim())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
 
 pub struct Orca {
     cmd_path: PathBuf,
@@ -68,38 +111,63 @@ pub struct Orca {
 }
 
 impl Orca {
+    /// Create a new `Orca`, panicking if the Orca executable cannot be found.
+    ///
+    /// See `Orca::try_new` for a non-panicking alternative.
+    #[allow(clippy::new_without_default)]
     pub fn new() -> Orca {
-        let p = match Orca::find_orca_executable() {
-            Ok(path) => path,
-            Err(msg) => panic!(msg),
-        };
+        match Orca::try_new() {
+            Ok(orca) => orca,
+            Err(e) => panic!("{}", e),
+        }
+    }
 
-        Orca {
+    /// Create a new `Orca`, returning `Error::OrcaNotFound` if the Orca executable cannot be found.
+    pub fn try_new() -> Result<Orca, Error> {
+        let p = Orca::find_orca_executable()?;
+        Ok(Orca {
             cmd_path: p,
             plotly_path: None,
-        }
+        })
     }
 
+    /// Create a new `Orca` that uses the plotly.js bundle at `plotly_path`, panicking if the Orca
+    /// executable cannot be found.
+    ///
+    /// See `Orca::try_from` for a non-panicking alternative.
     pub fn from<P: AsRef<Path>>(plotly_path: P) -> Orca {
+        match Orca::try_from(plotly_path) {
+            Ok(orca) => orca,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Create a new `Orca` that uses the plotly.js bundle at `plotly_path`, returning
+    /// `Error::OrcaNotFound` if the Orca executable cannot be found.
+    pub fn try_from<P: AsRef<Path>>(plotly_path: P) -> Result<Orca, Error> {
         let plotly_path = PathBuf::from(plotly_path.as_ref());
-        let p = match Orca::find_orca_executable() {
-            Ok(path) => path,
-            Err(msg) => panic!(msg),
-        };
+        let p = Orca::find_orca_executable()?;
 
         if !plotly_path.exists() {
-            return Orca {
+            return Ok(Orca {
                 cmd_path: p,
                 plotly_path: None,
-            };
+            });
         }
-        Orca {
+        Ok(Orca {
             cmd_path: p,
             plotly_path: Some(plotly_path),
-        }
+        })
     }
 
-    fn save(&self, dst: &Path, plotly_data: &str, image_format: &str, width: usize, height: usize) {
+    fn try_save(
+        &self,
+        dst: &Path,
+        plotly_data: &str,
+        image_format: &str,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
         let mut dst = PathBuf::from(dst);
         dst.set_extension(image_format);
 
@@ -109,22 +177,19 @@ impl Orca {
             .collect::<String>();
         plot_data_path.push_str(".json");
 
-        let mut temp = env::temp_dir();
-        temp.push(plot_data_path);
-        let temp_path = temp.to_str().unwrap();
+        let mut temp_path = env::temp_dir();
+        temp_path.push(plot_data_path);
         {
-            let mut file = File::create(temp_path).unwrap();
-            file.write_all(plotly_data.as_bytes())
-                .expect("failed to write html output");
-            file.flush().unwrap();
+            let mut file = File::create(&temp_path)?;
+            file.write_all(plotly_data.as_bytes())?;
+            file.flush()?;
         }
 
-        let orca_executable = self.cmd_path.to_str().unwrap();
-        let mut cmd = Command::new(orca_executable);
+        let mut cmd = Command::new(&self.cmd_path);
         cmd.arg("graph")
             .arg(&temp_path)
             .arg("-o")
-            .arg(dst.to_str().unwrap())
+            .arg(&dst)
             .arg("--width")
             .arg(format!("{}", width))
             .arg("--height")
@@ -132,17 +197,29 @@ impl Orca {
             .arg("--format")
             .arg(image_format);
 
-        match &self.plotly_path {
-            Some(p) => cmd
-                .arg("--plotly")
-                .arg(p.to_str().unwrap())
-                .output()
-                .unwrap(),
-            None => cmd.output().unwrap(),
-        };
+        if let Some(p) = &self.plotly_path {
+            cmd.arg("--plotly").arg(p);
+        }
+        let output = cmd.output();
+
+        // Best-effort cleanup, regardless of whether Orca succeeded, so that a failure to remove
+        // the temporary file does not mask the result of the conversion.
+        let _ = std::fs::remove_file(&temp_path);
+
+        let output = output?;
+        if !output.status.success() {
+            return Err(Error::OrcaFailed {
+                status: output.status,
+                stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            });
+        }
+        Ok(())
+    }
 
-        // Cleanup
-        std::fs::remove_file(temp_path).unwrap();
+    fn save(&self, dst: &Path, plotly_data: &str, image_format: &str, width: usize, height: usize) {
+        if let Err(e) = self.try_save(dst, plotly_data, image_format, width, height) {
+            panic!("{}", e);
+        }
     }
 
     pub fn save_png<P: AsRef<Path>>(&self, dst: P, plot_data: &str, width: usize, height: usize) {
@@ -169,34 +246,94 @@ impl Orca {
         self.save(dst.as_ref(), plot_data, "eps", width, height);
     }
 
+    pub fn try_save_png<P: AsRef<Path>>(
+        &self,
+        dst: P,
+        plot_data: &str,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        self.try_save(dst.as_ref(), plot_data, "png", width, height)
+    }
+
+    pub fn try_save_jpeg<P: AsRef<Path>>(
+        &self,
+        dst: P,
+        plot_data: &str,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        self.try_save(dst.as_ref(), plot_data, "jpeg", width, height)
+    }
+
+    pub fn try_save_webp<P: AsRef<Path>>(
+        &self,
+        dst: P,
+        plot_data: &str,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        self.try_save(dst.as_ref(), plot_data, "webp", width, height)
+    }
+
+    pub fn try_save_svg<P: AsRef<Path>>(
+        &self,
+        dst: P,
+        plot_data: &str,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        self.try_save(dst.as_ref(), plot_data, "svg", width, height)
+    }
+
+    pub fn try_save_pdf<P: AsRef<Path>>(
+        &self,
+        dst: P,
+        plot_data: &str,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        self.try_save(dst.as_ref(), plot_data, "pdf", width, height)
+    }
+
+    pub fn try_save_eps<P: AsRef<Path>>(
+        &self,
+        dst: P,
+        plot_data: &str,
+        width: usize,
+        height: usize,
+    ) -> Result<(), Error> {
+        self.try_save(dst.as_ref(), plot_data, "eps", width, height)
+    }
+
     #[cfg(target_os = "linux")]
-    fn find_orca_executable() -> Result<PathBuf, &'static str> {
+    fn find_orca_executable() -> Result<PathBuf, Error> {
         let p = PathBuf::from("/usr/bin/plotly_orca");
         if !p.exists() {
-            return Err(ORCA_INSTALLATION_INSTRUCTIONS);
+            return Err(Error::OrcaNotFound);
         }
         Ok(p)
     }
 
     #[cfg(target_os = "macos")]
-    fn find_orca_executable() -> Result<PathBuf, &'static str>  {
+    fn find_orca_executable() -> Result<PathBuf, Error> {
         let orca_path = PathBuf::from("/Applications/orca.app/Contents/MacOS/orca");
         if !orca_path.exists() {
-            return Err(ORCA_INSTALLATION_INSTRUCTIONS);
+            return Err(Error::OrcaNotFound);
         }
 
         Ok(orca_path)
     }
 
     #[cfg(target_os = "windows")]
-    fn find_orca_executable() -> Result<PathBuf, &'static str> {
-        let app_data = std::env::var_os("LOCALAPPDATA").expect("Could not expand LOCALAPPDATA");
+    fn find_orca_executable() -> Result<PathBuf, Error> {
+        let app_data = std::env::var_os("LOCALAPPDATA").ok_or(Error::OrcaNotFound)?;
         let mut orca_path = PathBuf::from(app_data);
         orca_path.push("Programs");
         orca_path.push("orca");
         orca_path.push("orca.exe");
         if !orca_path.exists() {
-            return Err(ORCA_INSTALLATION_INSTRUCTIONS);
+            return Err(Error::OrcaNotFound);
         }
 
         Ok(orca_path)
@@ -228,7 +365,7 @@ mod tests {
         assert!(dst.exists());
         match std::fs::remove_file(&dst) {
             Ok(_) => {}
-            Err(e) => panic!(format!("could not cleanup file, error: {}", e)),
+            Err(e) => panic!("could not cleanup file, error: {}", e),
         };
         assert!(!dst.exists());
     }
@@ -242,7 +379,7 @@ mod tests {
         assert!(dst.exists());
         match std::fs::remove_file(&dst) {
             Ok(_) => {}
-            Err(e) => panic!(format!("could not cleanup file, error: {}", e)),
+            Err(e) => panic!("could not cleanup file, error: {}", e),
         };
         assert!(!dst.exists());
     }
@@ -256,7 +393,7 @@ mod tests {
         assert!(dst.exists());
         match std::fs::remove_file(&dst) {
             Ok(_) => {}
-            Err(e) => panic!(format!("could not cleanup file, error: {}", e)),
+            Err(e) => panic!("could not cleanup file, error: {}", e),
         };
         assert!(!dst.exists());
     }
@@ -270,7 +407,7 @@ mod tests {
         assert!(dst.exists());
         match std::fs::remove_file(&dst) {
             Ok(_) => {}
-            Err(e) => panic!(format!("could not cleanup file, error: {}", e)),
+            Err(e) => panic!("could not cleanup file, error: {}", e),
         };
         assert!(!dst.exists());
     }
@@ -284,7 +421,7 @@ mod tests {
         assert!(dst.exists());
         match std::fs::remove_file(&dst) {
             Ok(_) => {}
-            Err(e) => panic!(format!("could not cleanup file, error: {}", e)),
+            Err(e) => panic!("could not cleanup file, error: {}", e),
         };
         assert!(!dst.exists());
     }
@@ -298,7 +435,7 @@ mod tests {
         assert!(dst.exists());
         match std::fs::remove_file(&dst) {
             Ok(_) => {}
-            Err(e) => panic!(format!("could not cleanup file, error: {}", e)),
+            Err(e) => panic!("could not cleanup file, error: {}", e),
         };
         assert!(!dst.exists());
     }